@@ -19,6 +19,29 @@ impl FrequencyBands {
     }
 }
 
+/// The result of `AudioAnalyzer::analyze_buffer`: a frequency-band frame
+/// and its timestamp for every FFT window slid across the input, plus the
+/// timestamp and intensity of every detected beat onset.
+#[derive(Debug, Clone)]
+pub struct AnalysisTimeline {
+    pub frames: Vec<(f32, FrequencyBands)>,
+    pub beats: Vec<(f32, f32)>,
+}
+
+/// Minimum total magnitude-spectrum energy before pitch detection is
+/// attempted; below this the signal is treated as silence/noise.
+const PITCH_NOISE_FLOOR: f32 = 1e-3;
+
+/// How much energy a sub-harmonic near half the detected peak frequency
+/// must carry, relative to the peak, before we prefer it instead (guards
+/// against HPS locking onto the first harmonic rather than the
+/// fundamental).
+const OCTAVE_GUARD_RATIO: f32 = 0.85;
+
+/// Highest harmonic multiple downsampled into the Harmonic Product
+/// Spectrum.
+const MAX_HPS_HARMONIC: usize = 5;
+
 /// Audio analyzer that performs FFT and extracts frequency information
 pub struct AudioAnalyzer {
     sample_rate: usize,
@@ -27,13 +50,14 @@ pub struct AudioAnalyzer {
     window: Vec<f32>,
     frequency_bands: Arc<Mutex<FrequencyBands>>,
     beat_detector: BeatDetector,
+    pitch_hz: Arc<Mutex<Option<f32>>>,
 }
 
 impl AudioAnalyzer {
     pub fn new(sample_rate: usize, fft_size: usize) -> Self {
         let planner = FftPlanner::new();
         let window = Self::create_hann_window(fft_size);
-        
+
         Self {
             sample_rate,
             fft_size,
@@ -41,6 +65,7 @@ impl AudioAnalyzer {
             window,
             frequency_bands: Arc::new(Mutex::new(FrequencyBands::new())),
             beat_detector: BeatDetector::new(sample_rate),
+            pitch_hz: Arc::new(Mutex::new(None)),
         }
     }
 
@@ -59,11 +84,60 @@ impl AudioAnalyzer {
             return FrequencyBands::new();
         }
 
+        let (bands, pitch) = self.analyze_window(&samples[..self.fft_size]);
+
+        // Update beat detector, advancing its sample clock by one FFT's
+        // worth of samples per call (there is no explicit hop in live mode).
+        self.beat_detector.process(bands.bass, self.fft_size);
+
+        // Store in shared state
+        if let Ok(mut fb) = self.frequency_bands.lock() {
+            *fb = bands;
+        }
+        if let Ok(mut p) = self.pitch_hz.lock() {
+            *p = pitch;
+        }
+
+        bands
+    }
+
+    /// Slide the FFT window across an entire pre-recorded buffer at a fixed
+    /// `hop_size`, independent of wall-clock time, and return every frame's
+    /// frequency bands alongside the timestamps and intensities of detected
+    /// beat onsets. Unlike `process_samples`, this runs the beat detector
+    /// from a clean slate driven purely by sample count, so the same buffer
+    /// always yields the same timeline no matter how fast it's analyzed.
+    pub fn analyze_buffer(&mut self, samples: &[f32], hop_size: usize) -> AnalysisTimeline {
+        let mut frames = Vec::new();
+        let mut beats = Vec::new();
+        self.beat_detector.reset();
+
+        let mut pos = 0;
+        while pos + self.fft_size <= samples.len() {
+            let (bands, _pitch) = self.analyze_window(&samples[pos..pos + self.fft_size]);
+            self.beat_detector.process(bands.bass, hop_size);
+
+            let timestamp_secs = pos as f32 / self.sample_rate as f32;
+            frames.push((timestamp_secs, bands));
+            if self.beat_detector.is_beat() {
+                beats.push((timestamp_secs, self.beat_detector.get_intensity()));
+            }
+
+            pos += hop_size;
+        }
+
+        AnalysisTimeline { frames, beats }
+    }
+
+    /// FFT one window of samples into frequency bands and an estimated
+    /// fundamental pitch, without touching the beat detector or shared
+    /// state - the common core of `process_samples` and `analyze_buffer`.
+    fn analyze_window(&mut self, window: &[f32]) -> (FrequencyBands, Option<f32>) {
         // Apply window function and convert to complex numbers
-        let mut input: Vec<Complex<f32>> = samples[..self.fft_size]
+        let mut input: Vec<Complex<f32>> = window
             .iter()
             .zip(&self.window)
-            .map(|(&sample, &window)| Complex::new(sample * window, 0.0))
+            .map(|(&sample, &w)| Complex::new(sample * w, 0.0))
             .collect();
 
         // Perform FFT
@@ -77,18 +151,64 @@ impl AudioAnalyzer {
             .map(|c| c.norm())
             .collect();
 
-        // Extract frequency bands
         let bands = self.extract_frequency_bands(&magnitudes);
-        
-        // Update beat detector
-        self.beat_detector.process(bands.bass);
-        
-        // Store in shared state
-        if let Ok(mut fb) = self.frequency_bands.lock() {
-            *fb = bands;
+        let pitch = self.estimate_pitch(&magnitudes);
+        (bands, pitch)
+    }
+
+    /// Estimate the fundamental frequency of `magnitudes` using the
+    /// Harmonic Product Spectrum: downsample the spectrum by each integer
+    /// factor 2..=5, multiply the downsampled copies bin-wise into the
+    /// original, and take the argmax bin of the product. A sub-harmonic
+    /// near half the peak frequency carrying comparable raw-spectrum
+    /// energy is preferred instead, to guard against locking onto the
+    /// first harmonic rather than the true fundamental.
+    fn estimate_pitch(&self, magnitudes: &[f32]) -> Option<f32> {
+        let total_energy: f32 = magnitudes.iter().sum();
+        if total_energy < PITCH_NOISE_FLOOR {
+            return None;
         }
 
-        bands
+        let freq_resolution = self.sample_rate as f32 / self.fft_size as f32;
+        let mut hps = magnitudes.to_vec();
+
+        for harmonic in 2..=MAX_HPS_HARMONIC {
+            for (i, bin) in hps.iter_mut().enumerate() {
+                match magnitudes.get(i * harmonic) {
+                    Some(&m) => *bin *= m,
+                    None => *bin = 0.0,
+                }
+            }
+        }
+
+        let mut peak_bin = hps
+            .iter()
+            .enumerate()
+            .skip(1) // ignore DC
+            .max_by(|a, b| a.1.partial_cmp(b.1).unwrap_or(std::cmp::Ordering::Equal))
+            .map(|(i, _)| i)?;
+
+        let half_bin = peak_bin / 2;
+        if half_bin >= 1
+            && magnitudes[half_bin] >= magnitudes.get(peak_bin).copied().unwrap_or(0.0) * OCTAVE_GUARD_RATIO
+        {
+            peak_bin = half_bin;
+        }
+
+        Some(peak_bin as f32 * freq_resolution)
+    }
+
+    /// The most recently detected fundamental frequency, or `None` if the
+    /// last processed buffer had no detectable pitch.
+    pub fn get_pitch(&self) -> Option<f32> {
+        self.pitch_hz.lock().ok().and_then(|p| *p)
+    }
+
+    /// The nearest equal-tempered note name (as a natural-note letter,
+    /// ignoring sharp/flat) and cents offset from that note, for the most
+    /// recently detected pitch.
+    pub fn get_note(&self) -> Option<(char, i32)> {
+        self.get_pitch().map(hz_to_note)
     }
 
     /// Extract frequency bands from magnitude spectrum
@@ -138,37 +258,55 @@ impl AudioAnalyzer {
     }
 }
 
-/// Simple beat detector based on bass energy
+/// Simple beat detector based on bass energy.
+///
+/// Time is tracked purely by accumulated sample count rather than
+/// `Instant`, so the detector is deterministic whether it's fed live, one
+/// frame per `Instant::now()` tick, or replayed faster-than-realtime over a
+/// decoded buffer.
 struct BeatDetector {
+    sample_rate: usize,
     history: Vec<f32>,
-    history_duration_secs: f32,  // Duration of history in seconds
-    last_beat_time: std::time::Instant,
-    start_time: std::time::Instant,
+    history_duration_secs: f32, // Duration of history in seconds
+    sample_clock: usize,        // Total samples advanced so far
+    last_beat_sample: usize,    // sample_clock value at the last detected beat
     beat_detected: bool,
     intensity: f32,
-    cooldown_duration: std::time::Duration,
+    cooldown_samples: usize, // 200ms worth of samples
 }
 
 impl BeatDetector {
-    fn new(_sample_rate: usize) -> Self {
+    fn new(sample_rate: usize) -> Self {
         Self {
+            sample_rate,
             history: Vec::new(),
-            history_duration_secs: 1.0,  // Keep 1 second of history
-            last_beat_time: std::time::Instant::now(),
-            start_time: std::time::Instant::now(),
+            history_duration_secs: 1.0, // Keep 1 second of history
+            sample_clock: 0,
+            last_beat_sample: 0,
             beat_detected: false,
             intensity: 0.0,
-            cooldown_duration: std::time::Duration::from_millis(200), // 200ms cooldown
+            cooldown_samples: sample_rate / 5, // 200ms cooldown
         }
     }
 
-    fn process(&mut self, bass_energy: f32) {
+    /// Reset the sample clock and history, for a fresh deterministic pass
+    /// over a buffer independent of any prior live session.
+    fn reset(&mut self) {
+        self.history.clear();
+        self.sample_clock = 0;
+        self.last_beat_sample = 0;
+        self.beat_detected = false;
+        self.intensity = 0.0;
+    }
+
+    fn process(&mut self, bass_energy: f32, hop_size: usize) {
         self.history.push(bass_energy);
-        
-        // Maintain approximately 1 second of history
-        // Assuming process is called at roughly video frame rate (~30-60 fps)
-        let max_history_size = (self.history_duration_secs * 50.0) as usize; // Assume ~50 calls per second
-        if self.history.len() > max_history_size {
+
+        // Maintain approximately 1 second of history, in frames
+        let max_history_size =
+            ((self.history_duration_secs * self.sample_rate as f32) / hop_size.max(1) as f32)
+                as usize;
+        if self.history.len() > max_history_size.max(1) {
             self.history.remove(0);
         }
 
@@ -176,6 +314,7 @@ impl BeatDetector {
         if self.history.len() < 20 {
             self.beat_detected = false;
             self.intensity = 0.0;
+            self.sample_clock += hop_size;
             return;
         }
 
@@ -186,22 +325,23 @@ impl BeatDetector {
             .map(|&x| (x - avg).powi(2))
             .sum::<f32>()
             / self.history.len() as f32;
-        
+
         let threshold = avg + variance.sqrt() * 1.5;
 
         // Detect beat with cooldown period
-        let now = std::time::Instant::now();
-        let cooldown_passed = now.duration_since(self.last_beat_time) > self.cooldown_duration;
-        
+        let cooldown_passed = self.sample_clock - self.last_beat_sample > self.cooldown_samples;
+
         if bass_energy > threshold && cooldown_passed {
             self.beat_detected = true;
-            self.last_beat_time = now;
+            self.last_beat_sample = self.sample_clock;
             self.intensity = (bass_energy - threshold) / threshold;
         } else {
             self.beat_detected = false;
             // Decay intensity
             self.intensity *= 0.95;
         }
+
+        self.sample_clock += hop_size;
     }
 
     fn is_beat(&self) -> bool {
@@ -213,6 +353,23 @@ impl BeatDetector {
     }
 }
 
+/// Natural-note letters for each of the 12 equal-tempered pitch classes,
+/// starting at C. Sharp/flat pitch classes reuse the letter below them,
+/// since a plain `char` can't carry an accidental.
+const NOTE_LETTERS: [char; 12] = [
+    'C', 'C', 'D', 'D', 'E', 'F', 'F', 'G', 'G', 'A', 'A', 'B',
+];
+
+/// Map a frequency in Hz to its nearest equal-tempered note letter and
+/// the cents offset from that note (A4 = 440 Hz).
+fn hz_to_note(hz: f32) -> (char, i32) {
+    let midi = 69.0 + 12.0 * (hz / 440.0).log2();
+    let nearest = midi.round();
+    let pitch_class = (((nearest as i32) % 12) + 12) % 12;
+    let cents = ((midi - nearest) * 100.0).round() as i32;
+    (NOTE_LETTERS[pitch_class as usize], cents)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -248,10 +405,93 @@ mod tests {
         let mut analyzer = AudioAnalyzer::new(44100, 2048);
         let samples: Vec<f32> = (0..2048).map(|_| 0.5).collect();
         let bands = analyzer.process_samples(&samples);
-        
+
         // Should return non-negative values
         assert!(bands.bass >= 0.0);
         assert!(bands.mid >= 0.0);
         assert!(bands.high >= 0.0);
     }
+
+    #[test]
+    fn test_pitch_detection_on_harmonic_tone() {
+        let sample_rate = 44100;
+        let fft_size = 2048;
+        let mut analyzer = AudioAnalyzer::new(sample_rate, fft_size);
+
+        // A bin-aligned fundamental with a couple of harmonics on top, like
+        // a real instrument or voice - HPS needs harmonic content to lock
+        // onto the fundamental rather than a sub-harmonic.
+        let freq_resolution = sample_rate as f32 / fft_size as f32;
+        let fundamental = 10.0 * freq_resolution;
+        let samples: Vec<f32> = (0..fft_size)
+            .map(|i| {
+                let t = i as f32 / sample_rate as f32;
+                (2.0 * std::f32::consts::PI * fundamental * t).sin()
+                    + 0.5 * (2.0 * std::f32::consts::PI * 2.0 * fundamental * t).sin()
+                    + 0.3 * (2.0 * std::f32::consts::PI * 3.0 * fundamental * t).sin()
+            })
+            .collect();
+
+        analyzer.process_samples(&samples);
+
+        let pitch = analyzer.get_pitch().expect("should detect a pitch");
+        assert!((pitch - fundamental).abs() < freq_resolution);
+    }
+
+    #[test]
+    fn test_pitch_detection_silence_returns_none() {
+        let mut analyzer = AudioAnalyzer::new(44100, 2048);
+        let samples: Vec<f32> = vec![0.0; 2048];
+        analyzer.process_samples(&samples);
+        assert_eq!(analyzer.get_pitch(), None);
+        assert_eq!(analyzer.get_note(), None);
+    }
+
+    #[test]
+    fn test_hz_to_note_a4_is_zero_cents() {
+        assert_eq!(hz_to_note(440.0), ('A', 0));
+    }
+
+    #[test]
+    fn test_hz_to_note_rounds_to_nearest_letter_and_cents() {
+        let (letter, cents) = hz_to_note(450.0);
+        assert_eq!(letter, 'A');
+        assert!(cents > 0);
+    }
+
+    #[test]
+    fn test_analyze_buffer_produces_one_frame_per_hop() {
+        let sample_rate = 44100;
+        let fft_size = 2048;
+        let hop_size = 1024;
+        let mut analyzer = AudioAnalyzer::new(sample_rate, fft_size);
+
+        let samples: Vec<f32> = (0..fft_size * 4).map(|_| 0.5).collect();
+        let timeline = analyzer.analyze_buffer(&samples, hop_size);
+
+        let expected_frames = (samples.len() - fft_size) / hop_size + 1;
+        assert_eq!(timeline.frames.len(), expected_frames);
+        assert_eq!(timeline.frames[1].0, hop_size as f32 / sample_rate as f32);
+    }
+
+    #[test]
+    fn test_analyze_buffer_is_deterministic_regardless_of_replay_speed() {
+        // Since the beat detector's clock is driven by sample count rather
+        // than Instant, analyzing the same buffer twice must always yield
+        // the same timeline, no matter how much wall-clock time elapses
+        // between calls.
+        let mut analyzer = AudioAnalyzer::new(44100, 2048);
+        let samples: Vec<f32> = (0..2048 * 8)
+            .map(|i| if i % 4410 < 100 { 1.0 } else { 0.05 })
+            .collect();
+
+        let first = analyzer.analyze_buffer(&samples, 512);
+        std::thread::sleep(std::time::Duration::from_millis(5));
+        let second = analyzer.analyze_buffer(&samples, 512);
+
+        assert_eq!(first.beats.len(), second.beats.len());
+        for (a, b) in first.beats.iter().zip(second.beats.iter()) {
+            assert_eq!(a.0, b.0);
+        }
+    }
 }
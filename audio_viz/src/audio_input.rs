@@ -3,10 +3,14 @@ use cpal::{Device, Stream, StreamConfig};
 use std::sync::{Arc, Mutex};
 use anyhow::{Result, Context};
 
+/// How many seconds of audio the ring buffer retains.
+const RING_BUFFER_SECS: usize = 2;
+
 /// Audio input handler that captures audio from a device
 pub struct AudioInput {
     device: Device,
     config: StreamConfig,
+    sample_format: cpal::SampleFormat,
     buffer: Arc<Mutex<Vec<f32>>>,
     stream: Option<Stream>,
 }
@@ -19,14 +23,16 @@ impl AudioInput {
             .default_input_device()
             .context("No input device available")?;
 
-        let config = device
+        let supported_config = device
             .default_input_config()
-            .context("Failed to get default input config")?
-            .into();
+            .context("Failed to get default input config")?;
+        let sample_format = supported_config.sample_format();
+        let config = supported_config.into();
 
         Ok(Self {
             device,
             config,
+            sample_format,
             buffer: Arc::new(Mutex::new(Vec::new())),
             stream: None,
         })
@@ -35,10 +41,15 @@ impl AudioInput {
     /// Start capturing audio
     pub fn start(&mut self) -> Result<()> {
         let buffer = self.buffer.clone();
+        let max_len = self.config.sample_rate.0 as usize * RING_BUFFER_SECS;
         let err_fn = |err| eprintln!("Audio stream error: {}", err);
 
-        // Build stream for f32 format
-        let stream = self.build_stream_f32(buffer, err_fn)?;
+        let stream = match self.sample_format {
+            cpal::SampleFormat::F32 => self.build_stream_f32(buffer, max_len, err_fn)?,
+            cpal::SampleFormat::I16 => self.build_stream_i16(buffer, max_len, err_fn)?,
+            cpal::SampleFormat::U16 => self.build_stream_u16(buffer, max_len, err_fn)?,
+            other => anyhow::bail!("Unsupported input sample format: {:?}", other),
+        };
 
         stream.play().context("Failed to play stream")?;
         self.stream = Some(stream);
@@ -48,6 +59,7 @@ impl AudioInput {
     fn build_stream_f32(
         &self,
         buffer: Arc<Mutex<Vec<f32>>>,
+        max_len: usize,
         err_fn: impl Fn(cpal::StreamError) + Send + 'static,
     ) -> Result<Stream>
     {
@@ -58,8 +70,67 @@ impl AudioInput {
                 move |data: &[f32], _: &_| {
                     if let Ok(mut buf) = buffer.lock() {
                         buf.extend_from_slice(data);
-                        // Keep only recent samples (2 seconds worth)
-                        let max_len = 88200; // 2 seconds at 44100 Hz
+                        if buf.len() > max_len {
+                            let drain_count = buf.len() - max_len;
+                            buf.drain(0..drain_count);
+                        }
+                    }
+                },
+                err_fn,
+                None,
+            )
+            .context("Failed to build input stream")?;
+
+        Ok(stream)
+    }
+
+    fn build_stream_i16(
+        &self,
+        buffer: Arc<Mutex<Vec<f32>>>,
+        max_len: usize,
+        err_fn: impl Fn(cpal::StreamError) + Send + 'static,
+    ) -> Result<Stream>
+    {
+        let stream = self
+            .device
+            .build_input_stream(
+                &self.config,
+                move |data: &[i16], _: &_| {
+                    if let Ok(mut buf) = buffer.lock() {
+                        buf.extend(data.iter().map(|&s| s as f32 / i16::MAX as f32));
+                        if buf.len() > max_len {
+                            let drain_count = buf.len() - max_len;
+                            buf.drain(0..drain_count);
+                        }
+                    }
+                },
+                err_fn,
+                None,
+            )
+            .context("Failed to build input stream")?;
+
+        Ok(stream)
+    }
+
+    fn build_stream_u16(
+        &self,
+        buffer: Arc<Mutex<Vec<f32>>>,
+        max_len: usize,
+        err_fn: impl Fn(cpal::StreamError) + Send + 'static,
+    ) -> Result<Stream>
+    {
+        let stream = self
+            .device
+            .build_input_stream(
+                &self.config,
+                move |data: &[u16], _: &_| {
+                    if let Ok(mut buf) = buffer.lock() {
+                        // u16 samples are unsigned, centered on 32768; shift
+                        // to signed and scale the same as i16.
+                        buf.extend(
+                            data.iter()
+                                .map(|&s| (s as f32 - 32768.0) / i16::MAX as f32),
+                        );
                         if buf.len() > max_len {
                             let drain_count = buf.len() - max_len;
                             buf.drain(0..drain_count);
@@ -121,8 +192,12 @@ impl AudioFileLoader {
                 let samples: Vec<i32> = reader.samples::<i32>()
                     .collect::<Result<Vec<_>, _>>()
                     .context("Failed to read int samples")?;
-                
-                let max_val = i32::MAX as f32;
+
+                // hound sign-extends every integer format into i32, so the
+                // true full-scale magnitude is 2^(bits_per_sample - 1), not
+                // i32::MAX - dividing by i32::MAX would scale 16-bit files
+                // down by roughly 32000x.
+                let max_val = (1i64 << (spec.bits_per_sample - 1)) as f32;
                 Ok(samples.iter().map(|&s| s as f32 / max_val).collect())
             }
         };
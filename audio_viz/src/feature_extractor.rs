@@ -0,0 +1,297 @@
+use rustfft::{num_complex::Complex, FftPlanner};
+
+/// Configuration for `FeatureExtractor`. `n_mfcc` selects how many DCT
+/// coefficients to keep; set it to `None` to stop after log-mel energies
+/// (e.g. for a mel-spectrogram-based model instead of classic MFCCs).
+#[derive(Debug, Clone, Copy)]
+pub struct FeatureExtractorConfig {
+    pub n_mels: usize,
+    pub n_mfcc: Option<usize>,
+    pub pre_emphasis: f32,
+}
+
+impl Default for FeatureExtractorConfig {
+    fn default() -> Self {
+        Self {
+            n_mels: 40,
+            n_mfcc: Some(13),
+            pre_emphasis: 0.97,
+        }
+    }
+}
+
+/// One analyzed frame of acoustic features.
+#[derive(Debug, Clone)]
+pub struct Frame {
+    pub timestamp_secs: f32,
+    pub mel_energies: Vec<f32>,
+    pub mfcc: Option<Vec<f32>>,
+    pub delta: Option<Vec<f32>>,
+    pub delta_delta: Option<Vec<f32>>,
+}
+
+fn hz_to_mel(hz: f32) -> f32 {
+    2595.0 * (1.0 + hz / 700.0).log10()
+}
+
+fn mel_to_hz(mel: f32) -> f32 {
+    700.0 * (10f32.powf(mel / 2595.0) - 1.0)
+}
+
+/// A bank of `n_mels` overlapping triangular filters spanning 0..Nyquist,
+/// each a weight vector over the `frame_size / 2 + 1` real-FFT bins.
+fn build_mel_filterbank(n_mels: usize, frame_size: usize, sample_rate: usize) -> Vec<Vec<f32>> {
+    let n_bins = frame_size / 2 + 1;
+    let nyquist = sample_rate as f32 / 2.0;
+    let mel_max = hz_to_mel(nyquist);
+
+    let mel_points: Vec<f32> = (0..n_mels + 2)
+        .map(|i| mel_max * i as f32 / (n_mels + 1) as f32)
+        .collect();
+    let bin_points: Vec<usize> = mel_points
+        .iter()
+        .map(|&mel| {
+            let hz = mel_to_hz(mel);
+            (((frame_size + 1) as f32 * hz / sample_rate as f32) as usize).min(n_bins - 1)
+        })
+        .collect();
+
+    (0..n_mels)
+        .map(|m| {
+            let (left, center, right) = (bin_points[m], bin_points[m + 1], bin_points[m + 2]);
+            let mut filter = vec![0.0f32; n_bins];
+            if center > left {
+                for bin in left..center {
+                    filter[bin] = (bin - left) as f32 / (center - left) as f32;
+                }
+            }
+            if right > center {
+                for bin in center..right {
+                    filter[bin] = (right - bin) as f32 / (right - center) as f32;
+                }
+            }
+            filter
+        })
+        .collect()
+}
+
+/// Type-II DCT of `input`, keeping the first `n_coeffs` coefficients -
+/// the step that turns log-mel filterbank energies into MFCCs by
+/// decorrelating them.
+fn dct2(input: &[f32], n_coeffs: usize) -> Vec<f32> {
+    let n = input.len() as f32;
+    (0..n_coeffs)
+        .map(|k| {
+            input
+                .iter()
+                .enumerate()
+                .map(|(i, &x)| {
+                    x * (std::f32::consts::PI / n * (i as f32 + 0.5) * k as f32).cos()
+                })
+                .sum::<f32>()
+                * 2.0
+        })
+        .collect()
+}
+
+/// Streaming acoustic front-end: pre-emphasis, Hamming-windowed 25 ms /
+/// 10 ms-hop frames, a real FFT, a triangular mel filterbank, log
+/// compression, and an optional DCT into MFCCs with delta/delta-delta
+/// coefficients. `push_samples` keeps all of this state (the unconsumed
+/// sample tail, the pre-emphasis carry, and the previous frame's MFCC and
+/// delta) across calls, so it can be fed directly from
+/// `AudioInput::get_samples` on every tick of a live capture loop.
+pub struct FeatureExtractor {
+    sample_rate: usize,
+    frame_size: usize,
+    hop_size: usize,
+    config: FeatureExtractorConfig,
+    planner: FftPlanner<f32>,
+    window: Vec<f32>,
+    mel_filterbank: Vec<Vec<f32>>,
+    carryover: Vec<f32>,
+    prev_raw_sample: f32,
+    frame_start_sample: usize,
+    prev_mfcc: Option<Vec<f32>>,
+    prev_delta: Option<Vec<f32>>,
+}
+
+impl FeatureExtractor {
+    /// A `FeatureExtractor` with the default config (40 mel bands, 13
+    /// MFCCs, 0.97 pre-emphasis).
+    pub fn new(sample_rate: usize) -> Self {
+        Self::with_config(sample_rate, FeatureExtractorConfig::default())
+    }
+
+    pub fn with_config(sample_rate: usize, config: FeatureExtractorConfig) -> Self {
+        let frame_size = (sample_rate as f32 * 0.025) as usize;
+        let hop_size = (sample_rate as f32 * 0.010) as usize;
+        let mel_filterbank = build_mel_filterbank(config.n_mels, frame_size, sample_rate);
+
+        Self {
+            sample_rate,
+            frame_size,
+            hop_size,
+            config,
+            planner: FftPlanner::new(),
+            window: Self::create_hamming_window(frame_size),
+            mel_filterbank,
+            carryover: Vec::new(),
+            prev_raw_sample: 0.0,
+            frame_start_sample: 0,
+            prev_mfcc: None,
+            prev_delta: None,
+        }
+    }
+
+    fn create_hamming_window(size: usize) -> Vec<f32> {
+        (0..size)
+            .map(|i| 0.54 - 0.46 * f32::cos(2.0 * std::f32::consts::PI * i as f32 / (size - 1) as f32))
+            .collect()
+    }
+
+    /// Push newly captured samples, applying pre-emphasis across the call
+    /// boundary, and return every complete frame the new samples finished.
+    pub fn push_samples(&mut self, samples: &[f32]) -> Vec<Frame> {
+        for &raw in samples {
+            self.carryover
+                .push(raw - self.config.pre_emphasis * self.prev_raw_sample);
+            self.prev_raw_sample = raw;
+        }
+
+        let mut frames = Vec::new();
+        while self.carryover.len() >= self.frame_size {
+            let frame = self.analyze_frame();
+            frames.push(frame);
+
+            let consumed = self.hop_size.min(self.carryover.len());
+            self.carryover.drain(..consumed);
+            self.frame_start_sample += consumed;
+        }
+        frames
+    }
+
+    fn analyze_frame(&mut self) -> Frame {
+        let mut spectrum: Vec<Complex<f32>> = self.carryover[..self.frame_size]
+            .iter()
+            .zip(&self.window)
+            .map(|(&s, &w)| Complex::new(s * w, 0.0))
+            .collect();
+
+        let fft = self.planner.plan_fft_forward(self.frame_size);
+        fft.process(&mut spectrum);
+
+        let power: Vec<f32> = spectrum
+            .iter()
+            .take(self.frame_size / 2 + 1)
+            .map(|c| c.norm_sqr())
+            .collect();
+
+        let mel_energies: Vec<f32> = self
+            .mel_filterbank
+            .iter()
+            .map(|filter| {
+                let energy: f32 = filter.iter().zip(&power).map(|(&w, &p)| w * p).sum();
+                (energy + 1e-10).ln()
+            })
+            .collect();
+
+        let mfcc = self.config.n_mfcc.map(|n| dct2(&mel_energies, n));
+        let delta = mfcc.as_ref().map(|m| Self::first_difference(m, &self.prev_mfcc));
+        let delta_delta = delta.as_ref().map(|d| Self::first_difference(d, &self.prev_delta));
+
+        let timestamp_secs = self.frame_start_sample as f32 / self.sample_rate as f32;
+
+        self.prev_mfcc = mfcc.clone();
+        self.prev_delta = delta.clone();
+
+        Frame {
+            timestamp_secs,
+            mel_energies,
+            mfcc,
+            delta,
+            delta_delta,
+        }
+    }
+
+    /// `current - previous`, element-wise, or all zeros for the first
+    /// frame (when there's no previous vector to difference against).
+    fn first_difference(current: &[f32], previous: &Option<Vec<f32>>) -> Vec<f32> {
+        match previous {
+            Some(prev) => current.iter().zip(prev).map(|(c, p)| c - p).collect(),
+            None => vec![0.0; current.len()],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mel_filterbank_shape_and_coverage() {
+        let filterbank = build_mel_filterbank(40, 400, 16000);
+        assert_eq!(filterbank.len(), 40);
+        for filter in &filterbank {
+            assert_eq!(filter.len(), 400 / 2 + 1);
+        }
+    }
+
+    #[test]
+    fn test_push_samples_produces_one_frame_per_hop_after_warmup() {
+        let sample_rate = 16000;
+        let mut extractor = FeatureExtractor::new(sample_rate);
+        let frame_size = (sample_rate as f32 * 0.025) as usize;
+        let hop_size = (sample_rate as f32 * 0.010) as usize;
+
+        let samples = vec![0.1f32; frame_size + 3 * hop_size];
+        let frames = extractor.push_samples(&samples);
+        assert_eq!(frames.len(), 4);
+        assert_eq!(frames[0].timestamp_secs, 0.0);
+        assert!((frames[1].timestamp_secs - hop_size as f32 / sample_rate as f32).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_push_samples_carries_partial_frame_across_calls() {
+        let sample_rate = 16000;
+        let mut extractor = FeatureExtractor::new(sample_rate);
+        let frame_size = (sample_rate as f32 * 0.025) as usize;
+
+        let first_half = vec![0.2f32; frame_size / 2];
+        assert!(extractor.push_samples(&first_half).is_empty());
+
+        let second_half = vec![0.2f32; frame_size / 2 + 1];
+        let frames = extractor.push_samples(&second_half);
+        assert_eq!(frames.len(), 1);
+    }
+
+    #[test]
+    fn test_first_frame_has_zero_delta() {
+        let sample_rate = 16000;
+        let mut extractor = FeatureExtractor::new(sample_rate);
+        let frame_size = (sample_rate as f32 * 0.025) as usize;
+
+        let samples: Vec<f32> = (0..frame_size).map(|i| (i as f32 * 0.1).sin()).collect();
+        let frames = extractor.push_samples(&samples);
+        let delta = frames[0].delta.as_ref().unwrap();
+        assert!(delta.iter().all(|&d| d == 0.0));
+    }
+
+    #[test]
+    fn test_mfcc_disabled_by_config_falls_back_to_log_mel_energies() {
+        let sample_rate = 16000;
+        let config = FeatureExtractorConfig {
+            n_mels: 20,
+            n_mfcc: None,
+            pre_emphasis: 0.97,
+        };
+        let mut extractor = FeatureExtractor::with_config(sample_rate, config);
+        let frame_size = (sample_rate as f32 * 0.025) as usize;
+        let samples = vec![0.3f32; frame_size];
+
+        let frames = extractor.push_samples(&samples);
+        assert_eq!(frames[0].mel_energies.len(), 20);
+        assert!(frames[0].mfcc.is_none());
+        assert!(frames[0].delta.is_none());
+    }
+}
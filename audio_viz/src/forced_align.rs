@@ -0,0 +1,135 @@
+//! Forced alignment of a known phoneme sequence onto captured audio.
+//!
+//! Given the phonemes a word transcribes to (via `durak::g2p::transcribe`)
+//! and the samples it was spoken into, `ForcedAligner` finds the
+//! time-stamped segment each phoneme most plausibly occupies. Audio is cut
+//! into overlapping ~25 ms frames every ~10 ms and scored against short-
+//! frame energy and zero-crossing rate: vowels want high energy and a low
+//! zero-crossing rate (periodic, voiced), while voiceless stops
+//! (`Phoneme::is_voiceless_stop`) want the opposite (closure-then-burst).
+//! A monotonic DTW-style dynamic program then finds the frame boundaries
+//! that assign every phoneme at least one frame, in order, at minimum
+//! total mismatch cost - the same problem shape as word segmentation.
+
+use durak::g2p::Phoneme;
+
+#[derive(Debug, Clone, Copy)]
+struct FrameFeatures {
+    start_sec: f32,
+    end_sec: f32,
+    energy: f32,
+    zero_crossing_rate: f32,
+}
+
+/// Aligns a phoneme sequence onto audio samples with configurable frame
+/// geometry, so the same aligner works for both the 44100 Hz live capture
+/// path and arbitrary-rate WAV files loaded via `AudioFileLoader`.
+pub struct ForcedAligner {
+    sample_rate: usize,
+    frame_size: usize,
+    hop_size: usize,
+}
+
+impl ForcedAligner {
+    /// A `ForcedAligner` using the standard 25 ms frame / 10 ms hop for
+    /// the given sample rate.
+    pub fn new(sample_rate: usize) -> Self {
+        Self::with_frame_params(
+            sample_rate,
+            (sample_rate as f32 * 0.025) as usize,
+            (sample_rate as f32 * 0.010) as usize,
+        )
+    }
+
+    /// A `ForcedAligner` with an explicit frame size and hop, in samples.
+    pub fn with_frame_params(sample_rate: usize, frame_size: usize, hop_size: usize) -> Self {
+        Self {
+            sample_rate,
+            frame_size,
+            hop_size,
+        }
+    }
+
+    fn extract_features(&self, samples: &[f32]) -> Vec<FrameFeatures> {
+        let mut frames = Vec::new();
+        let mut pos = 0;
+        while pos + self.frame_size <= samples.len() {
+            let window = &samples[pos..pos + self.frame_size];
+            let energy =
+                window.iter().map(|s| s * s).sum::<f32>() / window.len() as f32;
+            let zero_crossing_rate = window
+                .windows(2)
+                .filter(|pair| (pair[0] >= 0.0) != (pair[1] >= 0.0))
+                .count() as f32
+                / window.len() as f32;
+            frames.push(FrameFeatures {
+                start_sec: pos as f32 / self.sample_rate as f32,
+                end_sec: (pos + self.frame_size) as f32 / self.sample_rate as f32,
+                energy,
+                zero_crossing_rate,
+            });
+            pos += self.hop_size;
+        }
+        frames
+    }
+
+    /// How poorly `frame` fits `phoneme` - lower is better.
+    fn mismatch_cost(phoneme: Phoneme, frame: &FrameFeatures) -> f32 {
+        if phoneme.is_vowel() {
+            (1.0 - frame.energy).max(0.0) + frame.zero_crossing_rate
+        } else if phoneme.is_voiceless_stop() {
+            frame.energy.min(1.0 - frame.zero_crossing_rate)
+        } else {
+            0.0
+        }
+    }
+
+    /// Align `phonemes` onto `samples`, returning one timestamped segment
+    /// per phoneme. Returns an empty `Vec` if there are more phonemes than
+    /// frames, since every phoneme must claim at least one frame.
+    pub fn align(&self, phonemes: &[Phoneme], samples: &[f32]) -> Vec<(Phoneme, f32, f32)> {
+        let frames = self.extract_features(samples);
+        let (n, m) = (phonemes.len(), frames.len());
+        if n == 0 || m == 0 || n > m {
+            return Vec::new();
+        }
+
+        // best[i][j]: minimum cost of assigning phonemes[..i] to frames[..j].
+        // back[i][j]: the frame boundary where phonemes[i - 1]'s segment starts.
+        let mut best = vec![vec![f32::INFINITY; m + 1]; n + 1];
+        let mut back = vec![vec![0usize; m + 1]; n + 1];
+        best[0][0] = 0.0;
+
+        for i in 1..=n {
+            for j in i..=m {
+                for k in (i - 1)..j {
+                    if best[i - 1][k].is_infinite() {
+                        continue;
+                    }
+                    let segment_cost: f32 = (k..j)
+                        .map(|f| Self::mismatch_cost(phonemes[i - 1], &frames[f]))
+                        .sum();
+                    let cost = best[i - 1][k] + segment_cost;
+                    if cost < best[i][j] {
+                        best[i][j] = cost;
+                        back[i][j] = k;
+                    }
+                }
+            }
+        }
+
+        let mut boundaries = vec![m; n + 1];
+        let mut j = m;
+        for i in (1..=n).rev() {
+            boundaries[i - 1] = back[i][j];
+            j = boundaries[i - 1];
+        }
+
+        (0..n)
+            .map(|i| {
+                let (start, end) = (boundaries[i], boundaries[i + 1]);
+                (phonemes[i], frames[start].start_sec, frames[end - 1].end_sec)
+            })
+            .collect()
+    }
+}
@@ -1,5 +1,7 @@
 mod audio_analyzer;
 mod audio_input;
+mod feature_extractor;
+mod forced_align;
 mod renderer;
 
 use audio_analyzer::AudioAnalyzer;
@@ -0,0 +1,336 @@
+//! Rule-based Turkish grapheme-to-phoneme (G2P) transcription.
+//!
+//! Turkish orthography is close to phonemic, so a deterministic rule
+//! engine is enough for a broad transcription: each grapheme maps to a
+//! `Phoneme`, then a small ordered rule set handles the handful of
+//! context-sensitive alternations (soft-g, word-final devoicing, and
+//! front/back allophones of /l/, /k/, /g/). The phoneme sequence is the
+//! crate's pronunciation layer, bridging the morphology modules here with
+//! the audio pipeline's `AudioInput`/`AudioFileLoader` capture path.
+
+use crate::vowel_harmony::get_vowel_class;
+
+/// A single Turkish phoneme, tagged ARPABET-style where an equivalent
+/// exists (`Ch`, `Jh`, `Sh`, `Zh`, `Hh`) and with Turkish-specific tags
+/// for the sounds ARPABET has no code for (`Ih`/`Iy` for ı/i, `Oe`/`Ue`
+/// for ö/ü, `Gy`/`Ky` for the palatal g/k allophones, `Ll` for dark /l/).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Phoneme {
+    // Vowels
+    Aa,
+    Eh,
+    Ih,
+    Iy,
+    Ow,
+    Oe,
+    Uw,
+    Ue,
+    // Consonants
+    B,
+    P,
+    D,
+    T,
+    Jh,
+    Ch,
+    G,
+    Gy,
+    K,
+    Ky,
+    F,
+    Hh,
+    L,
+    Ll,
+    M,
+    N,
+    R,
+    S,
+    Sh,
+    V,
+    Y,
+    Zh,
+    Z,
+    /// Lengthens the immediately preceding vowel; surfaces from a deleted
+    /// ğ between two back (or otherwise non-front-front) vowels.
+    Length,
+}
+
+impl Phoneme {
+    /// True for the eight Turkish vowel phonemes.
+    pub fn is_vowel(self) -> bool {
+        matches!(
+            self,
+            Phoneme::Aa
+                | Phoneme::Eh
+                | Phoneme::Ih
+                | Phoneme::Iy
+                | Phoneme::Ow
+                | Phoneme::Oe
+                | Phoneme::Uw
+                | Phoneme::Ue
+        )
+    }
+
+    /// True for the voiceless stops p/ç/t/k and their palatal allophone,
+    /// mirroring `root_validator::VOICELESS_STOPS` at the phoneme level.
+    pub fn is_voiceless_stop(self) -> bool {
+        matches!(self, Phoneme::P | Phoneme::T | Phoneme::Ch | Phoneme::K | Phoneme::Ky)
+    }
+}
+
+/// Transcribe a single Turkish word to its phoneme sequence.
+///
+/// Applies, in order:
+/// 1. A per-grapheme mapping to its phoneme (c→Jh, ç→Ch, ş→Sh, j→Zh,
+///    y→Y, ...), with /k/, /g/, /l/ resolved to their front (palatal/clear)
+///    or back (plain/dark) allophone depending on the next letter when it
+///    is a vowel, or else on the nearest preceding vowel (e.g. at word end).
+/// 2. Soft-g (ğ): deleted and lengthens the preceding vowel, except
+///    between two front vowels where it instead surfaces as /j/.
+/// 3. Word-final devoicing of a trailing voiced obstruent (d→t, b→p,
+///    c→ç, g→k).
+pub fn transcribe(word: &str) -> Vec<Phoneme> {
+    let chars: Vec<char> = word.chars().collect();
+    let mut phonemes: Vec<Phoneme> = Vec::with_capacity(chars.len());
+
+    for (i, &c) in chars.iter().enumerate() {
+        let lower = c.to_lowercase().next().unwrap_or(c);
+
+        if lower == 'ğ' {
+            let prev_front = chars[..i]
+                .iter()
+                .rev()
+                .find_map(|&p| get_vowel_class(p))
+                .map(|v| v.is_front());
+            let next_front = chars[i + 1..]
+                .iter()
+                .find_map(|&n| get_vowel_class(n))
+                .map(|v| v.is_front());
+
+            if prev_front == Some(true) && next_front == Some(true) {
+                phonemes.push(Phoneme::Y);
+            } else {
+                phonemes.push(Phoneme::Length);
+            }
+            continue;
+        }
+
+        // The following vowel conditions the front/back allophone of
+        // g/k/l. When the next letter isn't a vowel (e.g. at word end), fall
+        // back to the preceding one - e.g. word-final "l" in "gel" is still the
+        // clear allophone, not the dark one, because "e" is front.
+        let front_context = chars
+            .get(i + 1)
+            .and_then(|&n| get_vowel_class(n))
+            .map(|v| v.is_front())
+            .or_else(|| {
+                chars[..i]
+                    .iter()
+                    .rev()
+                    .find_map(|&p| get_vowel_class(p))
+                    .map(|v| v.is_front())
+            });
+
+        match lower {
+            'a' => phonemes.push(Phoneme::Aa),
+            'e' => phonemes.push(Phoneme::Eh),
+            'ı' => phonemes.push(Phoneme::Ih),
+            'i' => phonemes.push(Phoneme::Iy),
+            'o' => phonemes.push(Phoneme::Ow),
+            'ö' => phonemes.push(Phoneme::Oe),
+            'u' => phonemes.push(Phoneme::Uw),
+            'ü' => phonemes.push(Phoneme::Ue),
+            'b' => phonemes.push(Phoneme::B),
+            'c' => phonemes.push(Phoneme::Jh),
+            'ç' => phonemes.push(Phoneme::Ch),
+            'd' => phonemes.push(Phoneme::D),
+            'f' => phonemes.push(Phoneme::F),
+            'g' => phonemes.push(if front_context == Some(true) {
+                Phoneme::Gy
+            } else {
+                Phoneme::G
+            }),
+            'h' => phonemes.push(Phoneme::Hh),
+            'j' => phonemes.push(Phoneme::Zh),
+            'k' => phonemes.push(if front_context == Some(true) {
+                Phoneme::Ky
+            } else {
+                Phoneme::K
+            }),
+            'l' => phonemes.push(if front_context == Some(true) {
+                Phoneme::L
+            } else {
+                Phoneme::Ll
+            }),
+            'm' => phonemes.push(Phoneme::M),
+            'n' => phonemes.push(Phoneme::N),
+            'p' => phonemes.push(Phoneme::P),
+            'q' => phonemes.push(Phoneme::K),
+            'r' => phonemes.push(Phoneme::R),
+            's' => phonemes.push(Phoneme::S),
+            'ş' => phonemes.push(Phoneme::Sh),
+            't' => phonemes.push(Phoneme::T),
+            'v' => phonemes.push(Phoneme::V),
+            'w' => phonemes.push(Phoneme::V),
+            'x' => {
+                phonemes.push(Phoneme::K);
+                phonemes.push(Phoneme::S);
+            }
+            'y' => phonemes.push(Phoneme::Y),
+            'z' => phonemes.push(Phoneme::Z),
+            _ => {}
+        }
+    }
+
+    if let Some(last) = phonemes.last_mut() {
+        let devoiced = match *last {
+            Phoneme::D => Some(Phoneme::T),
+            Phoneme::B => Some(Phoneme::P),
+            Phoneme::Jh => Some(Phoneme::Ch),
+            Phoneme::G => Some(Phoneme::K),
+            Phoneme::Gy => Some(Phoneme::Ky),
+            _ => None,
+        };
+        if let Some(d) = devoiced {
+            *last = d;
+        }
+    }
+
+    phonemes
+}
+
+/// The IPA segment a single phoneme surfaces as, on its own.
+fn ipa_segment(phoneme: Phoneme) -> &'static str {
+    match phoneme {
+        Phoneme::Aa => "a",
+        Phoneme::Eh => "e",
+        Phoneme::Ih => "ɯ",
+        Phoneme::Iy => "i",
+        Phoneme::Ow => "o",
+        Phoneme::Oe => "ø",
+        Phoneme::Uw => "u",
+        Phoneme::Ue => "y",
+        Phoneme::B => "b",
+        Phoneme::P => "p",
+        Phoneme::D => "d",
+        Phoneme::T => "t",
+        Phoneme::Jh => "d͡ʒ",
+        Phoneme::Ch => "t͡ʃ",
+        Phoneme::G => "ɡ",
+        Phoneme::Gy => "ɟ",
+        Phoneme::K => "k",
+        Phoneme::Ky => "c",
+        Phoneme::F => "f",
+        Phoneme::Hh => "h",
+        Phoneme::L => "l",
+        Phoneme::Ll => "ɫ",
+        Phoneme::M => "m",
+        Phoneme::N => "n",
+        Phoneme::R => "r",
+        Phoneme::S => "s",
+        Phoneme::Sh => "ʃ",
+        Phoneme::V => "v",
+        Phoneme::Y => "j",
+        Phoneme::Zh => "ʒ",
+        Phoneme::Z => "z",
+        Phoneme::Length => "",
+    }
+}
+
+/// Render a phoneme sequence back out as a broad IPA string, the reverse
+/// of `transcribe`. A `Length` phoneme lengthens the segment immediately
+/// before it rather than surfacing as a segment of its own.
+pub fn from_phonemes(phonemes: &[Phoneme]) -> String {
+    let mut out = String::new();
+    for &phoneme in phonemes {
+        if phoneme == Phoneme::Length {
+            out.push('ː');
+        } else {
+            out.push_str(ipa_segment(phoneme));
+        }
+    }
+    out
+}
+
+/// Transcribe a single Turkish word to a broad IPA string.
+/// See `transcribe` for the rules applied and `from_phonemes` for how the
+/// phoneme sequence is rendered back out to text.
+pub fn to_ipa(word: &str) -> String {
+    from_phonemes(&transcribe(word))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_affricate_and_sibilant_mapping() {
+        assert_eq!(to_ipa("can"), "d͡ʒan");
+        assert_eq!(to_ipa("yaş"), "jaʃ");
+    }
+
+    #[test]
+    fn test_soft_g_deletes_after_back_vowel_and_lengthens() {
+        assert_eq!(to_ipa("dağ"), "daː");
+    }
+
+    #[test]
+    fn test_soft_g_surfaces_as_j_between_front_vowels() {
+        assert_eq!(to_ipa("iğne"), "ijne");
+    }
+
+    #[test]
+    fn test_word_final_devoicing() {
+        // "kitab" also palatalizes the initial /k/ before /i/, see below.
+        assert_eq!(to_ipa("kitab"), "citap");
+        assert_eq!(to_ipa("ad"), "at");
+    }
+
+    #[test]
+    fn test_front_back_l_allophones() {
+        // /l/ before a front vowel stays clear [l]; before a back vowel it
+        // darkens to [ɫ].
+        assert_eq!(to_ipa("eli"), "eli");
+        assert_eq!(to_ipa("kola"), "koɫa");
+    }
+
+    #[test]
+    fn test_palatal_k_and_g_before_front_vowels() {
+        assert_eq!(to_ipa("kel"), "cel");
+        assert_eq!(to_ipa("kol"), "koɫ");
+        assert_eq!(to_ipa("gel"), "ɟel");
+        assert_eq!(to_ipa("göl"), "ɟøl");
+    }
+
+    #[test]
+    fn test_l_and_palatal_stops_use_preceding_vowel_when_word_final() {
+        // No following vowel to condition on, so a word-final l/k/g falls
+        // back to the preceding vowel's backness instead of always
+        // defaulting to the back/dark allophone.
+        assert_eq!(to_ipa("gel"), "ɟel");
+        assert_eq!(to_ipa("el"), "el");
+        assert_eq!(to_ipa("dil"), "dil");
+    }
+
+    #[test]
+    fn test_transcribe_produces_tagged_phoneme_sequence() {
+        assert_eq!(
+            transcribe("can"),
+            vec![Phoneme::Jh, Phoneme::Aa, Phoneme::N]
+        );
+    }
+
+    #[test]
+    fn test_transcribe_devoices_and_palatalizes_like_to_ipa() {
+        assert_eq!(
+            transcribe("kitab"),
+            vec![Phoneme::Ky, Phoneme::Iy, Phoneme::T, Phoneme::Aa, Phoneme::P]
+        );
+    }
+
+    #[test]
+    fn test_from_phonemes_round_trips_with_to_ipa() {
+        for word in ["can", "dağ", "iğne", "kitab", "kola", "göl"] {
+            assert_eq!(from_phonemes(&transcribe(word)), to_ipa(word));
+        }
+    }
+}
@@ -2,6 +2,15 @@ use pyo3::prelude::*;
 use std::collections::HashMap;
 use std::sync::OnceLock;
 
+pub mod g2p;
+pub mod morphotactics;
+pub mod phonetics;
+pub mod root_generator;
+pub mod root_validator;
+pub mod suffix_stripper;
+pub mod syllabify;
+pub mod vowel_harmony;
+
 static LEMMA_DICT: OnceLock<HashMap<&'static str, &'static str>> = OnceLock::new();
 
 fn get_lemma_dict() -> &'static HashMap<&'static str, &'static str> {
@@ -35,28 +44,52 @@ fn lookup_lemma(word: &str) -> Option<String> {
     dict.get(word).map(|s| s.to_string())
 }
 
-/// Tier 2: Heuristic Suffix Stripping
-/// Simple rule-based stripper for demonstration.
-/// In production, this would use a more complex state machine and vowel harmony checks.
+/// Pack a coarse phonetic encoding of a Turkish word into a `u64`, for
+/// fuzzy lemma lookup. See `phonetics::phonetic_hash`.
+#[pyfunction]
+fn phonetic_hash(word: &str) -> u64 {
+    phonetics::phonetic_hash(word)
+}
+
+/// Byte-position-weighted Hamming distance between two phonetic hashes.
+/// See `phonetics::phonetic_distance`.
+#[pyfunction]
+fn phonetic_distance(a: u64, b: u64) -> u32 {
+    phonetics::phonetic_distance(a, b)
+}
+
+/// Tier 1.5: Fuzzy Dictionary Lookup
+/// Falls back to the nearest phonetic match in the lemma dictionary when
+/// exact lookup misses, recovering from misspellings and orthographic
+/// variants.
+#[pyfunction]
+fn lookup_lemma_fuzzy(word: &str, max_distance: u32) -> Option<String> {
+    let dict = get_lemma_dict();
+    phonetics::lookup_lemma_fuzzy(word, dict, max_distance).map(|s| s.to_string())
+}
+
+/// Rule-based Turkish grapheme-to-phoneme transcription.
+/// See `g2p::to_ipa`.
+#[pyfunction]
+fn to_ipa(word: &str) -> String {
+    g2p::to_ipa(word)
+}
+
+/// Split a Turkish word into syllables by onset/coda cluster resolution.
+/// See `syllabify::syllabify`.
+#[pyfunction]
+fn syllabify(word: &str) -> Vec<String> {
+    syllabify::syllabify(word)
+}
+
+/// Tier 2: Finite-State Suffix Stripping
+/// Strips suffixes via the harmony- and mutation-aware state machine in
+/// `suffix_stripper`, which only strips a suffix whose resolved surface
+/// form both matches the word and harmonizes with the remaining stem, and
+/// reverses consonant softening (b→p, c→ç, d→t, ğ→k) at the new boundary.
 #[pyfunction]
 fn strip_suffixes(word: &str) -> String {
-    let suffixes = ["lar", "ler", "nin", "nın", "den", "dan", "du", "dün"];
-    let mut current = word.to_string();
-    
-    // Very naive recursive stripping for PoC
-    let mut changed = true;
-    while changed {
-        changed = false;
-        for suffix in suffixes {
-            if current.ends_with(suffix) && current.len() > suffix.len() + 2 { 
-                 // +2 constraint prevents over-stripping short roots
-                current = current[..current.len() - suffix.len()].to_string();
-                changed = true;
-                break; // Restart loop after stripping one suffix
-            }
-        }
-    }
-    current
+    suffix_stripper::analyze_suffixes(word).0
 }
 
 /// The internal Rust part of the Durak library.
@@ -64,6 +97,11 @@ fn strip_suffixes(word: &str) -> String {
 fn _durak_core(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(fast_normalize, m)?)?;
     m.add_function(wrap_pyfunction!(lookup_lemma, m)?)?;
+    m.add_function(wrap_pyfunction!(lookup_lemma_fuzzy, m)?)?;
+    m.add_function(wrap_pyfunction!(phonetic_hash, m)?)?;
+    m.add_function(wrap_pyfunction!(phonetic_distance, m)?)?;
     m.add_function(wrap_pyfunction!(strip_suffixes, m)?)?;
+    m.add_function(wrap_pyfunction!(syllabify, m)?)?;
+    m.add_function(wrap_pyfunction!(to_ipa, m)?)?;
     Ok(())
 }
@@ -1,303 +1,718 @@
-/// Turkish Morphotactics: Suffix Ordering Constraints
-/// 
-/// Implements Turkish morpheme sequence rules to validate suffix ordering.
-/// Prevents morphologically invalid sequences like *"kitap+da+lar" (Case before Plural).
-/// 
-/// # Turkish Suffix Slot Sequence (Nominal):
-/// 
-/// ROOT → [Plural] → [Possessive] → [Case] → [Copula]
-/// 
+/// Turkish Morphotactics: a finite-state suffix automaton
+///
+/// Models Turkish suffix ordering as a graph of named states connected by
+/// transitions, rather than a per-slot integer rank. This is closer to the
+/// classic two-level morphology design (cf. Oflazer's Turkish analyzer):
+/// every nominal or verbal slot is a state, and every slot is crossed even
+/// when its morpheme is phonologically empty (ε), so "elma" analyzes as
+/// `noun_S -ε-> a3pl_S -ε-> poss_S -ε-> case_S` rather than being treated
+/// as if the Plural/Possessive/Case slots simply don't apply.
+///
+/// # Turkish Suffix State Chain (Nominal):
+///
+/// noun_S → [a3pl_S] → [poss_S] → [case_S] → [cop_S]
+///
 /// Examples:
 /// - kitap+lar+ım+da (book+PL+1SG.POSS+LOC) ✓
-/// - ev+im+de (house+1SG.POSS+LOC) ✓
+/// - ev+ε+ε+ε (house, bare nominative) ✓
 /// - *kitap+da+lar (Case before Plural) ✗
-/// - *ev+lar+da+im (Case before Possessive) ✗
-/// 
-/// # Turkish Suffix Slot Sequence (Verbal):
-/// 
-/// ROOT → [Voice] → [Negation] → [Tense/Aspect] → [Person] → [Copula]
-/// 
+///
+/// # Turkish Suffix State Chain (Verbal):
+///
+/// verbRoot_S → [voice_S] → [neg_S] → [tense_S] → [person_S] → [vcop_S]
+///
 /// Examples:
 /// - gel+di+m (come+PAST+1SG) ✓
 /// - yap+ıl+dı (do+PASS+PAST) ✓
 /// - *gel+m+di (Person before Tense) ✗
-
-use std::collections::HashMap;
-
-/// Morpheme slot types for Turkish nominal morphology
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
-pub enum NominalSlot {
-    /// Plural markers: -lar, -ler
-    Plural = 1,
-    /// Possessive markers: -ım, -im, -um, -üm, etc.
-    Possessive = 2,
-    /// Case markers: -da, -de, -dan, -den, -ın, -in, etc.
-    Case = 3,
-    /// Copula (to be): -dır, -dir, etc.
-    Copula = 4,
+///
+/// # Derivational Bridges
+///
+/// Derivational suffixes (-lA, -mA, -Iş, ...) cross from one paradigm's
+/// root state into the other's, so the following suffixes are validated
+/// against the new paradigm's chain instead of being rejected as mixed:
+///
+/// noun_S -[le: Verb^DB]-> verbRoot_S      (göz → göz+le-, "to eye")
+/// verbRoot_S -[me/iş: Noun^DB]-> noun_S   (gel → gel+me/gel+iş, "coming")
+///
+/// Examples:
+/// - göz+le+di+m (eye+VBLZ+PAST+1SG, noun→verb) ✓
+/// - gel+me+ler+i (come+NMLZ+PL+P3SG, verb→noun) ✓
+use crate::vowel_harmony::realize;
+use std::collections::HashSet;
+
+/// A named state in the morphotactic automaton, e.g. `noun_S`, `a3pl_S`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct StateId(pub &'static str);
+
+/// Root attributes that gate conditioned transitions (e.g. a root that is
+/// inherently plural, like "insan" in some dialectal uses, or a compound
+/// head that takes a fixed third-person possessive).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RootAttribute {
+    /// Root is semantically plural already; blocks an explicit -lAr.
+    ImplicitPlural,
+    /// Root is the head of a -sI compound (e.g. "su+ borusu"); enables the
+    /// compound third-person possessive edge alongside the regular
+    /// possessive edges.
+    CompoundP3sg,
+    /// Root ends in a voiceless consonant (ç, f, h, k, p, s, ş, t).
+    VoicelessFinal,
 }
 
-/// Morpheme slot types for Turkish verbal morphology
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
-pub enum VerbalSlot {
-    /// Voice markers: -ıl, -il (passive), -ın, -in (reflexive)
-    Voice = 1,
-    /// Negation: -ma, -me
-    Negation = 2,
-    /// Tense/Aspect: -di, -dı, -yor, -acak, etc.
-    TenseAspect = 3,
-    /// Person markers: -m, -n, -k, -z, etc.
-    Person = 4,
-    /// Copula: -dır, -dir
-    Copula = 5,
+type Condition = fn(&HashSet<RootAttribute>) -> bool;
+
+fn has(attr: RootAttribute) -> Condition {
+    match attr {
+        RootAttribute::ImplicitPlural => |a| a.contains(&RootAttribute::ImplicitPlural),
+        RootAttribute::CompoundP3sg => |a| a.contains(&RootAttribute::CompoundP3sg),
+        RootAttribute::VoicelessFinal => |a| a.contains(&RootAttribute::VoicelessFinal),
+    }
 }
 
-/// Suffix classification for morphotactic validation
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum SuffixSlot {
-    Nominal(NominalSlot),
-    Verbal(VerbalSlot),
-    /// Unknown suffix (not validated)
-    Unknown,
+fn not_have(attr: RootAttribute) -> Condition {
+    match attr {
+        RootAttribute::ImplicitPlural => |a| !a.contains(&RootAttribute::ImplicitPlural),
+        RootAttribute::CompoundP3sg => |a| !a.contains(&RootAttribute::CompoundP3sg),
+        RootAttribute::VoicelessFinal => |a| !a.contains(&RootAttribute::VoicelessFinal),
+    }
 }
 
-/// Map Turkish suffixes to their morphotactic slots
-pub struct MorphotacticClassifier {
-    nominal_map: HashMap<&'static str, NominalSlot>,
-    verbal_map: HashMap<&'static str, VerbalSlot>,
+/// One edge of the automaton: a surface suffix (or `""` for ε) that moves
+/// the analysis from `from` to `to`, optionally gated by a root attribute
+/// condition.
+#[derive(Clone, Copy)]
+pub struct Transition {
+    pub from: StateId,
+    pub to: StateId,
+    /// A literal example of this transition's surface realization; empty
+    /// string = ε. Used directly by `validate_sequence`/`find_path`, which
+    /// reason about slot order rather than surface phonology.
+    pub surface: &'static str,
+    /// The abstract archiphoneme template (see `vowel_harmony::realize`)
+    /// this transition instantiates, when its surface form varies by
+    /// harmony/assimilation context. `None` for invariant suffixes.
+    pub template: Option<&'static str>,
+    /// The interlinear feature tag emitted for this transition, e.g.
+    /// `"A3pl"`, `"P1sg"`, `"Abl"`. Empty for ε-transitions, except the
+    /// zero third-person agreement edge, which is tagged `"3sg"`.
+    pub gloss: &'static str,
+    /// Optional gate on root attributes; `None` means unconditional.
+    pub condition: Option<Condition>,
 }
 
-impl MorphotacticClassifier {
-    /// Create a new morphotactic classifier with predefined suffix rules
-    pub fn new() -> Self {
-        let mut nominal_map = HashMap::new();
-        let mut verbal_map = HashMap::new();
-
-        // Nominal Plural
-        nominal_map.insert("lar", NominalSlot::Plural);
-        nominal_map.insert("ler", NominalSlot::Plural);
-
-        // Nominal Possessive
-        nominal_map.insert("ım", NominalSlot::Possessive);
-        nominal_map.insert("im", NominalSlot::Possessive);
-        nominal_map.insert("um", NominalSlot::Possessive);
-        nominal_map.insert("üm", NominalSlot::Possessive);
-        nominal_map.insert("ımız", NominalSlot::Possessive);
-        nominal_map.insert("imiz", NominalSlot::Possessive);
-        nominal_map.insert("umuz", NominalSlot::Possessive);
-        nominal_map.insert("ümüz", NominalSlot::Possessive);
-
-        // Nominal Case (Locative, Ablative, Genitive, Dative, Accusative)
-        nominal_map.insert("da", NominalSlot::Case);
-        nominal_map.insert("de", NominalSlot::Case);
-        nominal_map.insert("ta", NominalSlot::Case);
-        nominal_map.insert("te", NominalSlot::Case);
-        nominal_map.insert("dan", NominalSlot::Case);
-        nominal_map.insert("den", NominalSlot::Case);
-        nominal_map.insert("tan", NominalSlot::Case);
-        nominal_map.insert("ten", NominalSlot::Case);
-        nominal_map.insert("ın", NominalSlot::Case);
-        nominal_map.insert("in", NominalSlot::Case);
-        nominal_map.insert("un", NominalSlot::Case);
-        nominal_map.insert("ün", NominalSlot::Case);
-        nominal_map.insert("nın", NominalSlot::Case);
-        nominal_map.insert("nin", NominalSlot::Case);
-        nominal_map.insert("nun", NominalSlot::Case);
-        nominal_map.insert("nün", NominalSlot::Case);
-        nominal_map.insert("a", NominalSlot::Case);
-        nominal_map.insert("e", NominalSlot::Case);
-        nominal_map.insert("ya", NominalSlot::Case);
-        nominal_map.insert("ye", NominalSlot::Case);
-        nominal_map.insert("ı", NominalSlot::Case);
-        nominal_map.insert("i", NominalSlot::Case);
-        nominal_map.insert("u", NominalSlot::Case);
-        nominal_map.insert("ü", NominalSlot::Case);
-
-        // Verbal Voice
-        verbal_map.insert("ıl", VerbalSlot::Voice);
-        verbal_map.insert("il", VerbalSlot::Voice);
-        verbal_map.insert("ul", VerbalSlot::Voice);
-        verbal_map.insert("ül", VerbalSlot::Voice);
-        verbal_map.insert("ın", VerbalSlot::Voice);
-        verbal_map.insert("in", VerbalSlot::Voice);
-        verbal_map.insert("un", VerbalSlot::Voice);
-        verbal_map.insert("ün", VerbalSlot::Voice);
-
-        // Verbal Negation
-        verbal_map.insert("ma", VerbalSlot::Negation);
-        verbal_map.insert("me", VerbalSlot::Negation);
-
-        // Verbal Tense/Aspect
-        verbal_map.insert("di", VerbalSlot::TenseAspect);
-        verbal_map.insert("dı", VerbalSlot::TenseAspect);
-        verbal_map.insert("du", VerbalSlot::TenseAspect);
-        verbal_map.insert("dü", VerbalSlot::TenseAspect);
-        verbal_map.insert("ti", VerbalSlot::TenseAspect);
-        verbal_map.insert("tı", VerbalSlot::TenseAspect);
-        verbal_map.insert("tu", VerbalSlot::TenseAspect);
-        verbal_map.insert("tü", VerbalSlot::TenseAspect);
-        verbal_map.insert("yor", VerbalSlot::TenseAspect);
-        verbal_map.insert("acak", VerbalSlot::TenseAspect);
-        verbal_map.insert("ecek", VerbalSlot::TenseAspect);
-        verbal_map.insert("mış", VerbalSlot::TenseAspect);
-        verbal_map.insert("miş", VerbalSlot::TenseAspect);
-        verbal_map.insert("muş", VerbalSlot::TenseAspect);
-        verbal_map.insert("müş", VerbalSlot::TenseAspect);
-
-        // Verbal Person
-        verbal_map.insert("m", VerbalSlot::Person);
-        verbal_map.insert("n", VerbalSlot::Person);
-        verbal_map.insert("k", VerbalSlot::Person);
-        verbal_map.insert("z", VerbalSlot::Person);
-        verbal_map.insert("ım", VerbalSlot::Person);
-        verbal_map.insert("im", VerbalSlot::Person);
-        verbal_map.insert("um", VerbalSlot::Person);
-        verbal_map.insert("üm", VerbalSlot::Person);
-        verbal_map.insert("nız", VerbalSlot::Person);
-        verbal_map.insert("niz", VerbalSlot::Person);
-        verbal_map.insert("nuz", VerbalSlot::Person);
-        verbal_map.insert("nüz", VerbalSlot::Person);
-
-        Self {
-            nominal_map,
-            verbal_map,
+impl Transition {
+    fn is_epsilon(&self) -> bool {
+        self.surface.is_empty()
+    }
+
+    fn allowed(&self, attrs: &HashSet<RootAttribute>) -> bool {
+        self.condition.map_or(true, |cond| cond(attrs))
+    }
+
+    /// The surface form this transition produces after `stem`, resolving
+    /// its template (if any) via the harmony/assimilation engine.
+    fn realized_surface(&self, stem: &str) -> String {
+        match self.template {
+            Some(template) => realize(template, stem),
+            None => self.surface.to_string(),
         }
     }
 
-    /// Classify a suffix into its morphotactic slot
-    /// Note: Some suffixes (e.g., -ım, -im, -um, -üm) can be both nominal possessive
-    /// and verbal person markers. We prefer verbal interpretation when validating sequences.
-    pub fn classify(&self, suffix: &str) -> SuffixSlot {
-        // Check verbal first to handle ambiguous suffixes (e.g., -um can be both)
-        if let Some(&slot) = self.verbal_map.get(suffix) {
-            return SuffixSlot::Verbal(slot);
+    /// Whether `input` could be *some* surface realization of this
+    /// transition, independent of any particular stem. Used by
+    /// `validate_sequence`, which reasons about slot order over abstract
+    /// suffix tokens rather than a concrete word.
+    fn matches_abstractly(&self, input: &str) -> bool {
+        self.surface == input
+            || self
+                .template
+                .is_some_and(|t| template_variants(t).iter().any(|v| v == input))
+    }
+}
+
+/// Expand an archiphoneme template into the finite set of concrete surface
+/// strings it can produce, independent of any stem (e.g. `"{D}{A}"` →
+/// `["da", "de", "ta", "te"]`). This only enumerates form, not harmony
+/// agreement between occurrences of the same archiphoneme in one template,
+/// so it is a superset used purely for stem-agnostic order checking; actual
+/// word analysis resolves templates against a real stem via `realize`.
+fn template_variants(template: &str) -> Vec<String> {
+    let mut variants = vec![String::new()];
+    let mut chars = template.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c != '{' {
+            for v in variants.iter_mut() {
+                v.push(c);
+            }
+            continue;
         }
 
-        // Check nominal
-        if let Some(&slot) = self.nominal_map.get(suffix) {
-            return SuffixSlot::Nominal(slot);
+        let mut code = String::new();
+        for c2 in chars.by_ref() {
+            if c2 == '}' {
+                break;
+            }
+            code.push(c2);
         }
 
-        SuffixSlot::Unknown
+        let options: &[&str] = match code.as_str() {
+            "A" => &["a", "e"],
+            "I" => &["ı", "i", "u", "ü"],
+            "D" => &["d", "t"],
+            "Cy" => &["y", ""],
+            "Cn" => &["n", ""],
+            "Cs" => &["s", ""],
+            _ => &[""],
+        };
+
+        variants = variants
+            .iter()
+            .flat_map(|v| options.iter().map(move |opt| format!("{v}{opt}")))
+            .collect();
     }
 
-    /// Validate a sequence of suffixes
-    /// Returns true if the sequence is morphotactically valid
-    /// 
-    /// For ambiguous suffixes (e.g., -ım can be possessive or person marker),
-    /// we try both nominal and verbal interpretations and accept if either is valid.
-    pub fn validate_sequence(&self, suffixes: &[&str]) -> bool {
-        if suffixes.is_empty() {
-            return true;
-        }
+    variants
+}
 
-        // Check if any suffix appears in both paradigms (ambiguous)
-        let has_ambiguous = suffixes.iter().any(|s| {
-            self.nominal_map.contains_key(s) && self.verbal_map.contains_key(s)
-        });
+/// Named nominal states, in slot order.
+pub const NOUN_ROOT: StateId = StateId("noun_S");
+pub const A3PL_S: StateId = StateId("a3pl_S");
+pub const POSS_S: StateId = StateId("poss_S");
+pub const CASE_S: StateId = StateId("case_S");
+pub const NOM_COP_S: StateId = StateId("cop_S");
+
+/// Named verbal states, in slot order.
+pub const VERB_ROOT: StateId = StateId("verbRoot_S");
+pub const VOICE_S: StateId = StateId("voice_S");
+pub const NEG_S: StateId = StateId("neg_S");
+pub const TENSE_S: StateId = StateId("tense_S");
+pub const PERSON_S: StateId = StateId("person_S");
+pub const VERB_COP_S: StateId = StateId("vcop_S");
+
+/// The Turkish morphotactic automaton: named states plus the transitions
+/// between them, searched as a path rather than compared as ranks.
+pub struct Morphotactics {
+    transitions: Vec<Transition>,
+}
 
-        if has_ambiguous {
-            // Try both paradigms
-            let nominal_valid = self.try_validate_as_nominal(suffixes);
-            let verbal_valid = self.try_validate_as_verbal(suffixes);
-            return nominal_valid || verbal_valid;
+impl Morphotactics {
+    /// Build the automaton with the standard nominal and verbal slot chains.
+    pub fn new() -> Self {
+        let mut transitions = Vec::new();
+
+        macro_rules! edge {
+            ($from:expr, $to:expr, $surface:expr, $template:expr, $gloss:expr) => {
+                transitions.push(Transition {
+                    from: $from,
+                    to: $to,
+                    surface: $surface,
+                    template: $template,
+                    gloss: $gloss,
+                    condition: None,
+                });
+            };
+            ($from:expr, $to:expr, $surface:expr, $template:expr, $gloss:expr, $cond:expr) => {
+                transitions.push(Transition {
+                    from: $from,
+                    to: $to,
+                    surface: $surface,
+                    template: $template,
+                    gloss: $gloss,
+                    condition: Some($cond),
+                });
+            };
         }
 
-        // No ambiguity: classify and validate normally
-        let slots: Vec<SuffixSlot> = suffixes.iter().map(|s| self.classify(s)).collect();
+        // Nominal chain: every slot has an ε-edge so a bare root validates.
+        edge!(
+            NOUN_ROOT, A3PL_S, "lar", Some("l{A}r"), "A3pl",
+            not_have(RootAttribute::ImplicitPlural)
+        );
+        edge!(NOUN_ROOT, A3PL_S, "", None, "");
+
+        edge!(A3PL_S, POSS_S, "ım", Some("{I}m"), "P1sg");
+        edge!(A3PL_S, POSS_S, "ın", Some("{I}n"), "P2sg");
+        edge!(A3PL_S, POSS_S, "ı", Some("{Cs}{I}"), "P3sg");
+        edge!(A3PL_S, POSS_S, "ımız", Some("{I}m{I}z"), "P1pl");
+        edge!(A3PL_S, POSS_S, "ınız", Some("{I}n{I}z"), "P2pl");
+        edge!(A3PL_S, POSS_S, "ları", Some("l{A}r{I}"), "P3pl");
+        edge!(
+            A3PL_S, POSS_S, "sı", Some("s{I}"), "P3sg^DB",
+            has(RootAttribute::CompoundP3sg)
+        );
+        edge!(A3PL_S, POSS_S, "", None, "");
+
+        edge!(POSS_S, CASE_S, "da", Some("{D}{A}"), "Loc");
+        edge!(POSS_S, CASE_S, "dan", Some("{D}{A}n"), "Abl");
+        edge!(POSS_S, CASE_S, "nın", Some("{Cn}{I}n"), "Gen");
+        edge!(POSS_S, CASE_S, "a", Some("{Cy}{A}"), "Dat");
+        edge!(POSS_S, CASE_S, "ı", Some("{Cy}{I}"), "Acc");
+        edge!(POSS_S, CASE_S, "", None, "");
+
+        edge!(CASE_S, NOM_COP_S, "dır", Some("d{I}r"), "Cop");
+        edge!(CASE_S, NOM_COP_S, "", None, "");
+
+        // Verbal chain: same ε-skippable shape, except that the tense slot
+        // has no ε-edge.
+        edge!(VERB_ROOT, VOICE_S, "ıl", Some("{I}l"), "Pass");
+        edge!(VERB_ROOT, VOICE_S, "ın", Some("{I}n"), "Refl");
+        edge!(VERB_ROOT, VOICE_S, "", None, "");
+
+        edge!(VOICE_S, NEG_S, "ma", Some("m{A}"), "Neg");
+        edge!(VOICE_S, NEG_S, "", None, "");
+
+        edge!(NEG_S, TENSE_S, "dı", Some("{D}{I}"), "Past");
+        edge!(NEG_S, TENSE_S, "yor", None, "Prog");
+        edge!(NEG_S, TENSE_S, "acak", Some("{A}c{A}k"), "Fut");
+        edge!(NEG_S, TENSE_S, "mış", Some("m{I}ş"), "Evid");
+
+        edge!(TENSE_S, PERSON_S, "m", None, "1sg");
+        edge!(TENSE_S, PERSON_S, "n", None, "2sg");
+        edge!(TENSE_S, PERSON_S, "k", None, "1pl");
+        edge!(TENSE_S, PERSON_S, "niz", Some("n{I}z"), "2pl");
+        edge!(TENSE_S, PERSON_S, "lar", Some("l{A}r"), "3pl");
+        edge!(TENSE_S, PERSON_S, "", None, "3sg");
+
+        edge!(PERSON_S, VERB_COP_S, "dır", Some("d{I}r"), "Cop");
+        edge!(PERSON_S, VERB_COP_S, "", None, "");
+
+        // Derivational bridges: these cross from one paradigm's root state
+        // into the other's, so the suffixes that follow are then validated
+        // against the *new* paradigm's chain rather than rejected as mixed.
+        edge!(NOUN_ROOT, VERB_ROOT, "le", Some("l{A}"), "Verb^DB");
+        edge!(VERB_ROOT, NOUN_ROOT, "me", Some("m{A}"), "Noun^DB");
+        edge!(VERB_ROOT, NOUN_ROOT, "iş", Some("{I}ş"), "Noun^DB");
+
+        Self { transitions }
+    }
 
-        // If any suffix is unknown, we can't validate → allow (permissive mode)
-        if slots.iter().any(|s| matches!(s, SuffixSlot::Unknown)) {
-            return true;
-        }
+    fn outgoing(&self, state: StateId) -> impl Iterator<Item = &Transition> {
+        self.transitions.iter().filter(move |t| t.from == state)
+    }
 
-        // Check if all slots are from the same paradigm (nominal or verbal)
-        let all_nominal = slots.iter().all(|s| matches!(s, SuffixSlot::Nominal(_)));
-        let all_verbal = slots.iter().all(|s| matches!(s, SuffixSlot::Verbal(_)));
+    /// Search for a path from `start` that consumes exactly `suffixes` in
+    /// order, freely taking ε-transitions along the way. Returns the
+    /// sequence of states visited (including `start`) on success.
+    pub fn find_path(
+        &self,
+        start: StateId,
+        suffixes: &[&str],
+        attrs: &HashSet<RootAttribute>,
+    ) -> Option<Vec<StateId>> {
+        self.search(start, suffixes, attrs, vec![start])
+    }
 
-        if !all_nominal && !all_verbal {
-            // Mixed nominal/verbal slots → invalid
-            return false;
+    fn search(
+        &self,
+        state: StateId,
+        remaining: &[&str],
+        attrs: &HashSet<RootAttribute>,
+        path: Vec<StateId>,
+    ) -> Option<Vec<StateId>> {
+        if remaining.is_empty() {
+            return Some(path);
         }
 
-        // Validate ordering within the paradigm
-        if all_nominal {
-            self.validate_nominal_sequence(&slots)
-        } else {
-            self.validate_verbal_sequence(&slots)
+        for transition in self.outgoing(state) {
+            if !transition.allowed(attrs) {
+                continue;
+            }
+
+            if transition.is_epsilon() {
+                let mut next_path = path.clone();
+                next_path.push(transition.to);
+                if let Some(found) = self.search(transition.to, remaining, attrs, next_path) {
+                    return Some(found);
+                }
+            } else if transition.matches_abstractly(remaining[0]) {
+                let mut next_path = path.clone();
+                next_path.push(transition.to);
+                if let Some(found) =
+                    self.search(transition.to, &remaining[1..], attrs, next_path)
+                {
+                    return Some(found);
+                }
+            }
         }
+
+        None
+    }
+
+    /// Validate a suffix sequence starting from a given paradigm root state.
+    /// Returns true if *any* path through the graph accepts the sequence.
+    pub fn validate_sequence(
+        &self,
+        start: StateId,
+        suffixes: &[&str],
+        attrs: &HashSet<RootAttribute>,
+    ) -> bool {
+        self.find_path(start, suffixes, attrs).is_some()
+    }
+
+    /// Convenience entry point mirroring the old classifier API: try the
+    /// nominal chain, then the verbal chain, with no special attributes.
+    pub fn validate_either(&self, suffixes: &[&str]) -> bool {
+        let attrs = HashSet::new();
+        self.validate_sequence(NOUN_ROOT, suffixes, &attrs)
+            || self.validate_sequence(VERB_ROOT, suffixes, &attrs)
     }
 
-    /// Try to validate sequence as purely nominal
-    fn try_validate_as_nominal(&self, suffixes: &[&str]) -> bool {
-        let mut slots = Vec::new();
-        for suffix in suffixes {
-            if let Some(&slot) = self.nominal_map.get(suffix) {
-                slots.push(SuffixSlot::Nominal(slot));
-            } else {
-                // Not in nominal paradigm
-                return false;
+    /// Segment a whole inflected word into every valid morpheme analysis.
+    ///
+    /// Drives the automaton over `word`, at each state resolving each
+    /// outgoing transition's template against the stem consumed so far
+    /// (via the harmony/assimilation engine) and trying it as a prefix of
+    /// what remains. Tries candidate roots of every length that contain at
+    /// least one vowel, against both the nominal and the verbal chain, so
+    /// genuinely ambiguous words surface more than one `Analysis`. Returns
+    /// an empty vec when nothing parses.
+    pub fn analyze(&self, word: &str) -> Vec<Analysis> {
+        let mut results = Vec::new();
+        let chars: Vec<char> = word.chars().collect();
+
+        for split in 1..chars.len() {
+            let root: String = chars[..split].iter().collect();
+            if !root.chars().any(|c| crate::vowel_harmony::get_vowel_class(c).is_some()) {
+                continue;
+            }
+            let rest: String = chars[split..].iter().collect();
+
+            for (start, category) in [(NOUN_ROOT, "Noun"), (VERB_ROOT, "Verb")] {
+                self.analyze_from(&root, &rest, start, category, Vec::new(), &mut results);
             }
         }
-        self.validate_nominal_sequence(&slots)
-    }
-
-    /// Try to validate sequence as purely verbal
-    fn try_validate_as_verbal(&self, suffixes: &[&str]) -> bool {
-        let mut slots = Vec::new();
-        for suffix in suffixes {
-            if let Some(&slot) = self.verbal_map.get(suffix) {
-                slots.push(SuffixSlot::Verbal(slot));
-            } else {
-                // Not in verbal paradigm
-                return false;
+
+        // The whole word as a bare root (no suffixes at all).
+        if chars.iter().any(|c| crate::vowel_harmony::get_vowel_class(*c).is_some()) {
+            for (start, category) in [(NOUN_ROOT, "Noun"), (VERB_ROOT, "Verb")] {
+                self.analyze_from(word, "", start, category, Vec::new(), &mut results);
             }
         }
-        self.validate_verbal_sequence(&slots)
+
+        results
     }
 
-    /// Validate nominal suffix ordering
-    fn validate_nominal_sequence(&self, slots: &[SuffixSlot]) -> bool {
-        let mut last_slot_rank = 0;
+    fn analyze_from(
+        &self,
+        root: &str,
+        remaining: &str,
+        state: StateId,
+        category: &'static str,
+        morphemes: Vec<Morpheme>,
+        results: &mut Vec<Analysis>,
+    ) {
+        if remaining.is_empty() {
+            let gloss = render_gloss(root, category, &morphemes);
+            results.push(Analysis { root: root.to_string(), category, morphemes, gloss });
+            return;
+        }
 
-        for slot in slots {
-            if let SuffixSlot::Nominal(nominal_slot) = slot {
-                let current_rank = *nominal_slot as usize;
+        let stem: String = std::iter::once(root)
+            .chain(morphemes.iter().map(|m| m.surface.as_str()))
+            .collect();
+        let attrs = HashSet::new();
 
-                // Each slot must come after or equal to the previous one
-                // (Equal allows multiple suffixes in the same slot, e.g., -lar-lar)
-                if current_rank < last_slot_rank {
-                    return false; // Out of order
-                }
+        for transition in self.outgoing(state) {
+            if !transition.allowed(&attrs) {
+                continue;
+            }
 
-                last_slot_rank = current_rank;
+            if transition.is_epsilon() {
+                self.analyze_from(root, remaining, transition.to, category, morphemes.clone(), results);
+                continue;
+            }
+
+            let realized = transition.realized_surface(&stem);
+            if !realized.is_empty() && remaining.starts_with(&realized) {
+                let mut next_morphemes = morphemes.clone();
+                next_morphemes.push(Morpheme { surface: realized.clone(), gloss: transition.gloss });
+                self.analyze_from(
+                    root,
+                    &remaining[realized.len()..],
+                    transition.to,
+                    category,
+                    next_morphemes,
+                    results,
+                );
             }
         }
+    }
+}
 
-        true
+/// One segmented morpheme in an `Analysis`: its realized surface form and
+/// its interlinear feature gloss (e.g. `"ler"` / `"A3pl"`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Morpheme {
+    pub surface: String,
+    pub gloss: &'static str,
+}
+
+/// One full segmentation of a word produced by `Morphotactics::analyze`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Analysis {
+    pub root: String,
+    /// The paradigm this analysis was parsed under ("Noun" or "Verb").
+    pub category: &'static str,
+    pub morphemes: Vec<Morpheme>,
+    /// Interlinear gloss, e.g. `"ev +Noun +A3pl(ler) +P1pl(imiz) +Abl(den)"`.
+    pub gloss: String,
+}
+
+fn render_gloss(root: &str, category: &str, morphemes: &[Morpheme]) -> String {
+    let mut out = format!("{root} +{category}");
+    for m in morphemes {
+        if !m.gloss.is_empty() {
+            out.push_str(&format!(" +{}({})", m.gloss, m.surface));
+        }
     }
+    out
+}
 
-    /// Validate verbal suffix ordering
-    fn validate_verbal_sequence(&self, slots: &[SuffixSlot]) -> bool {
-        let mut last_slot_rank = 0;
+/// Grammatical number for the nominal plural slot.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Number {
+    Sg,
+    Pl,
+}
 
-        for slot in slots {
-            if let SuffixSlot::Verbal(verbal_slot) = slot {
-                let current_rank = *verbal_slot as usize;
+/// Person/number combination, shared by the possessive and verbal
+/// agreement slots.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Person {
+    P1Sg,
+    P2Sg,
+    P3Sg,
+    P1Pl,
+    P2Pl,
+    P3Pl,
+}
 
-                if current_rank < last_slot_rank {
-                    return false; // Out of order
-                }
+impl Person {
+    /// The possessive-slot gloss tag this person maps to (e.g. `"P1sg"`).
+    fn possessive_gloss(self) -> &'static str {
+        match self {
+            Person::P1Sg => "P1sg",
+            Person::P2Sg => "P2sg",
+            Person::P3Sg => "P3sg",
+            Person::P1Pl => "P1pl",
+            Person::P2Pl => "P2pl",
+            Person::P3Pl => "P3pl",
+        }
+    }
 
-                last_slot_rank = current_rank;
-            }
+    /// The verbal agreement-slot gloss tag this person maps to (e.g. `"1sg"`).
+    fn agreement_gloss(self) -> &'static str {
+        match self {
+            Person::P1Sg => "1sg",
+            Person::P2Sg => "2sg",
+            Person::P3Sg => "3sg",
+            Person::P1Pl => "1pl",
+            Person::P2Pl => "2pl",
+            Person::P3Pl => "3pl",
         }
+    }
+}
+
+/// Nominal case, matching the `POSS_S` → `CASE_S` transitions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Case {
+    /// Bare nominative (no case suffix).
+    Nom,
+    Loc,
+    Abl,
+    Gen,
+    Dat,
+    Acc,
+}
+
+/// Feature bundle for generating an inflected noun, e.g.
+/// `(kitap, Pl, Some(P1Sg), Loc)` → `"kitaplarımda"`.
+pub struct NominalSpec {
+    pub number: Number,
+    pub possessor: Option<Person>,
+    pub case: Case,
+}
+
+/// Voice marking for the verbal voice slot.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Voice {
+    Active,
+    Passive,
+    Reflexive,
+}
+
+/// Tense/aspect marking for the verbal tense slot.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TenseAspect {
+    Past,
+    Progressive,
+    Future,
+    Evidential,
+}
 
-        true
+/// Feature bundle for generating an inflected verb, e.g.
+/// `(gel, Active, false, Past, P1Sg)` → `"geldim"`.
+pub struct VerbalSpec {
+    pub voice: Voice,
+    pub negation: bool,
+    pub tense_aspect: TenseAspect,
+    pub person: Person,
+}
+
+/// A requested feature bundle has no corresponding path through the
+/// automaton (the same invalid orderings `validate_sequence` rejects).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NoSuchPath(String);
+
+impl std::fmt::Display for NoSuchPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "no morphotactic path for {}", self.0)
     }
 }
 
-impl Default for MorphotacticClassifier {
+impl std::error::Error for NoSuchPath {}
+
+impl Morphotactics {
+    fn transition_by_gloss(
+        &self,
+        from: StateId,
+        to: StateId,
+        gloss: &str,
+        attrs: &HashSet<RootAttribute>,
+    ) -> Option<&Transition> {
+        self.transitions
+            .iter()
+            .find(|t| t.from == from && t.to == to && t.gloss == gloss && t.allowed(attrs))
+    }
+
+    fn epsilon_transition(
+        &self,
+        from: StateId,
+        to: StateId,
+        attrs: &HashSet<RootAttribute>,
+    ) -> Option<&Transition> {
+        self.transitions
+            .iter()
+            .find(|t| t.from == from && t.to == to && t.is_epsilon() && t.allowed(attrs))
+    }
+
+    fn append_step(&self, stem: &mut String, transition: &Transition) {
+        let realized = transition.realized_surface(stem);
+        stem.push_str(&realized);
+    }
+
+    /// Generate the inflected surface form of `root` for a nominal feature
+    /// bundle, walking the same automaton `analyze` parses with, so
+    /// generation and validation share one graph. `attrs` gates the same
+    /// root-specific conditions `validate_sequence` checks (e.g. a root with
+    /// `ImplicitPlural` has no path to `Number::Pl`). Returns an error when
+    /// `spec` has no valid path for `attrs` (e.g. a possessor/case
+    /// combination the graph doesn't license).
+    pub fn generate(
+        &self,
+        root: &str,
+        spec: &NominalSpec,
+        attrs: &HashSet<RootAttribute>,
+    ) -> Result<String, NoSuchPath> {
+        let mut stem = root.to_string();
+
+        let plural = match spec.number {
+            Number::Pl => self
+                .transition_by_gloss(NOUN_ROOT, A3PL_S, "A3pl", attrs)
+                .ok_or_else(|| NoSuchPath("Number::Pl".to_string()))?,
+            Number::Sg => self
+                .epsilon_transition(NOUN_ROOT, A3PL_S, attrs)
+                .ok_or_else(|| NoSuchPath("Number::Sg".to_string()))?,
+        };
+        self.append_step(&mut stem, plural);
+
+        let possession = match spec.possessor {
+            Some(person) => self
+                .transition_by_gloss(A3PL_S, POSS_S, person.possessive_gloss(), attrs)
+                .ok_or_else(|| NoSuchPath(format!("possessor {:?}", person)))?,
+            None => self
+                .epsilon_transition(A3PL_S, POSS_S, attrs)
+                .ok_or_else(|| NoSuchPath("no possessor".to_string()))?,
+        };
+        self.append_step(&mut stem, possession);
+
+        let case = match spec.case {
+            Case::Nom => self
+                .epsilon_transition(POSS_S, CASE_S, attrs)
+                .ok_or_else(|| NoSuchPath("Case::Nom".to_string()))?,
+            other => {
+                let gloss = match other {
+                    Case::Loc => "Loc",
+                    Case::Abl => "Abl",
+                    Case::Gen => "Gen",
+                    Case::Dat => "Dat",
+                    Case::Acc => "Acc",
+                    Case::Nom => unreachable!(),
+                };
+                self.transition_by_gloss(POSS_S, CASE_S, gloss, attrs)
+                    .ok_or_else(|| NoSuchPath(format!("case {:?}", other)))?
+            }
+        };
+        self.append_step(&mut stem, case);
+
+        Ok(stem)
+    }
+
+    /// Generate the inflected surface form of `root` for a verbal feature
+    /// bundle. See `generate` for the nominal counterpart.
+    pub fn generate_verbal(
+        &self,
+        root: &str,
+        spec: &VerbalSpec,
+        attrs: &HashSet<RootAttribute>,
+    ) -> Result<String, NoSuchPath> {
+        let mut stem = root.to_string();
+
+        let voice = match spec.voice {
+            Voice::Active => self
+                .epsilon_transition(VERB_ROOT, VOICE_S, attrs)
+                .ok_or_else(|| NoSuchPath("Voice::Active".to_string()))?,
+            Voice::Passive => self
+                .transition_by_gloss(VERB_ROOT, VOICE_S, "Pass", attrs)
+                .ok_or_else(|| NoSuchPath("Voice::Passive".to_string()))?,
+            Voice::Reflexive => self
+                .transition_by_gloss(VERB_ROOT, VOICE_S, "Refl", attrs)
+                .ok_or_else(|| NoSuchPath("Voice::Reflexive".to_string()))?,
+        };
+        self.append_step(&mut stem, voice);
+
+        let negation = if spec.negation {
+            self.transition_by_gloss(VOICE_S, NEG_S, "Neg", attrs)
+                .ok_or_else(|| NoSuchPath("negation".to_string()))?
+        } else {
+            self.epsilon_transition(VOICE_S, NEG_S, attrs)
+                .ok_or_else(|| NoSuchPath("no negation".to_string()))?
+        };
+        self.append_step(&mut stem, negation);
+
+        let tense_gloss = match spec.tense_aspect {
+            TenseAspect::Past => "Past",
+            TenseAspect::Progressive => "Prog",
+            TenseAspect::Future => "Fut",
+            TenseAspect::Evidential => "Evid",
+        };
+        let tense = self
+            .transition_by_gloss(NEG_S, TENSE_S, tense_gloss, attrs)
+            .ok_or_else(|| NoSuchPath(format!("tense {:?}", spec.tense_aspect)))?;
+        self.append_step(&mut stem, tense);
+
+        let person = self
+            .transition_by_gloss(TENSE_S, PERSON_S, spec.person.agreement_gloss(), attrs)
+            .ok_or_else(|| NoSuchPath(format!("person {:?}", spec.person)))?;
+        self.append_step(&mut stem, person);
+
+        Ok(stem)
+    }
+}
+
+impl Default for Morphotactics {
     fn default() -> Self {
         Self::new()
     }
@@ -307,133 +722,218 @@ impl Default for MorphotacticClassifier {
 mod tests {
     use super::*;
 
+    fn no_attrs() -> HashSet<RootAttribute> {
+        HashSet::new()
+    }
+
     #[test]
-    fn test_suffix_classification() {
-        let classifier = MorphotacticClassifier::new();
-
-        // Nominal (unambiguous)
-        assert!(matches!(
-            classifier.classify("lar"),
-            SuffixSlot::Nominal(NominalSlot::Plural)
-        ));
-        assert!(matches!(
-            classifier.classify("da"),
-            SuffixSlot::Nominal(NominalSlot::Case)
-        ));
-
-        // Verbal (unambiguous)
-        assert!(matches!(
-            classifier.classify("di"),
-            SuffixSlot::Verbal(VerbalSlot::TenseAspect)
-        ));
-        assert!(matches!(
-            classifier.classify("yor"),
-            SuffixSlot::Verbal(VerbalSlot::TenseAspect)
-        ));
-
-        // Ambiguous suffixes (classified as verbal due to precedence)
-        assert!(matches!(
-            classifier.classify("ım"),
-            SuffixSlot::Verbal(VerbalSlot::Person)
-        ));
-        assert!(matches!(
-            classifier.classify("m"),
-            SuffixSlot::Verbal(VerbalSlot::Person)
-        ));
-
-        // Unknown
-        assert!(matches!(classifier.classify("xyz"), SuffixSlot::Unknown));
+    fn test_bare_root_takes_all_epsilon_edges() {
+        let fst = Morphotactics::new();
+        // elma -> Noun:ε Pnon:ε Nom:ε
+        assert!(fst.validate_sequence(NOUN_ROOT, &[], &no_attrs()));
     }
 
     #[test]
     fn test_valid_nominal_sequences() {
-        let classifier = MorphotacticClassifier::new();
-
-        // kitap+lar+ım+da (Plural → Possessive → Case)
-        assert!(classifier.validate_sequence(&["lar", "ım", "da"]));
-
-        // ev+im+de (Possessive → Case)
-        assert!(classifier.validate_sequence(&["im", "de"]));
+        let fst = Morphotactics::new();
+        // kitap+lar+ım+da
+        assert!(fst.validate_sequence(NOUN_ROOT, &["lar", "ım", "da"], &no_attrs()));
+        // ev+im+de
+        assert!(fst.validate_sequence(NOUN_ROOT, &["im", "de"], &no_attrs()));
+    }
 
-        // masa+lar (Plural only)
-        assert!(classifier.validate_sequence(&["lar"]));
+    #[test]
+    fn test_invalid_nominal_ordering() {
+        let fst = Morphotactics::new();
+        // *kitap+da+lar (Case before Plural)
+        assert!(!fst.validate_sequence(NOUN_ROOT, &["da", "lar"], &no_attrs()));
+        // *masa+ım+lar (Possessive before Plural)
+        assert!(!fst.validate_sequence(NOUN_ROOT, &["ım", "lar"], &no_attrs()));
+    }
 
-        // Empty sequence
-        assert!(classifier.validate_sequence(&[]));
+    #[test]
+    fn test_valid_verbal_sequences() {
+        let fst = Morphotactics::new();
+        // gel+di+m
+        assert!(fst.validate_sequence(VERB_ROOT, &["di", "m"], &no_attrs()));
+        // yap+ıl+dı
+        assert!(fst.validate_sequence(VERB_ROOT, &["ıl", "dı"], &no_attrs()));
     }
 
     #[test]
-    fn test_invalid_nominal_sequences() {
-        let classifier = MorphotacticClassifier::new();
+    fn test_invalid_verbal_ordering() {
+        let fst = Morphotactics::new();
+        // *gel+m+di (Person before Tense)
+        assert!(!fst.validate_sequence(VERB_ROOT, &["m", "di"], &no_attrs()));
+    }
 
-        // *kitap+da+lar (Case before Plural - INVALID)
-        assert!(!classifier.validate_sequence(&["da", "lar"]));
+    #[test]
+    fn test_derivational_bridge_noun_to_verb() {
+        let fst = Morphotactics::new();
+        // göz+le+di+m (eye+VBLZ+PAST+1SG): "le" derives a verb from a noun
+        // root, so the tense/person suffixes that follow validate against
+        // the verbal chain.
+        assert!(fst.validate_sequence(NOUN_ROOT, &["le", "di", "m"], &no_attrs()));
+    }
 
-        // *ev+lar+im+da+ım (Case before Possessive - INVALID)
-        assert!(!classifier.validate_sequence(&["lar", "da", "ım"]));
+    #[test]
+    fn test_derivational_bridge_verb_to_noun() {
+        let fst = Morphotactics::new();
+        // gel+me+ler+i (come+NMLZ+PL+P3SG): "me" derives a noun from a verb
+        // root, so the plural/possessive suffixes that follow validate
+        // against the nominal chain.
+        assert!(fst.validate_sequence(VERB_ROOT, &["me", "ler", "i"], &no_attrs()));
+        // gel+iş+i (the -Iş nominalizer, then a bare possessive)
+        assert!(fst.validate_sequence(VERB_ROOT, &["iş", "i"], &no_attrs()));
+    }
 
-        // *masa+ım+lar (Possessive before Plural - INVALID)
-        assert!(!classifier.validate_sequence(&["ım", "lar"]));
+    #[test]
+    fn test_cross_paradigm_rejection() {
+        let fst = Morphotactics::new();
+        assert!(!fst.validate_either(&["lar", "di"]));
     }
 
     #[test]
-    fn test_valid_verbal_sequences() {
-        let classifier = MorphotacticClassifier::new();
+    fn test_implicit_plural_blocks_explicit_plural() {
+        let fst = Morphotactics::new();
+        let mut attrs = HashSet::new();
+        attrs.insert(RootAttribute::ImplicitPlural);
+
+        // A root already marked plural cannot also take -lar...
+        assert!(!fst.validate_sequence(NOUN_ROOT, &["lar", "da"], &attrs));
+        // ...but still accepts the case suffix directly via the ε-edge.
+        assert!(fst.validate_sequence(NOUN_ROOT, &["da"], &attrs));
+    }
 
-        // gel+di+m (Tense → Person)
-        assert!(classifier.validate_sequence(&["di", "m"]));
+    #[test]
+    fn test_locative_template_covers_all_four_allomorphs() {
+        let fst = Morphotactics::new();
+        for suffix in ["da", "de", "ta", "te"] {
+            assert!(fst.validate_sequence(NOUN_ROOT, &[suffix], &no_attrs()));
+        }
+    }
 
-        // yap+ıl+dı (Voice → Tense)
-        assert!(classifier.validate_sequence(&["ıl", "dı"]));
+    #[test]
+    fn test_find_path_reports_visited_states() {
+        let fst = Morphotactics::new();
+        let path = fst
+            .find_path(NOUN_ROOT, &["lar", "da"], &no_attrs())
+            .expect("should find a path");
+        assert_eq!(path.first(), Some(&NOUN_ROOT));
+        assert_eq!(path.last(), Some(&CASE_S));
+    }
 
-        // bak+ma+dı+m (Negation → Tense → Person)
-        assert!(classifier.validate_sequence(&["ma", "dı", "m"]));
+    #[test]
+    fn test_analyze_nominal_word() {
+        let fst = Morphotactics::new();
+        let analyses = fst.analyze("evlerimizden");
+
+        let found = analyses.iter().find(|a| {
+            a.root == "ev"
+                && a.category == "Noun"
+                && a.morphemes.iter().map(|m| m.surface.as_str()).collect::<Vec<_>>()
+                    == ["ler", "imiz", "den"]
+        });
+        let analysis = found.expect("should analyze evlerimizden as ev+ler+imiz+den");
+        assert_eq!(analysis.gloss, "ev +Noun +A3pl(ler) +P1pl(imiz) +Abl(den)");
     }
 
     #[test]
-    fn test_invalid_verbal_sequences() {
-        let classifier = MorphotacticClassifier::new();
+    fn test_analyze_verbal_word() {
+        let fst = Morphotactics::new();
+        let analyses = fst.analyze("gelmediler");
+
+        let found = analyses.iter().find(|a| {
+            a.root == "gel"
+                && a.category == "Verb"
+                && a.morphemes.iter().map(|m| m.surface.as_str()).collect::<Vec<_>>()
+                    == ["me", "di", "ler"]
+        });
+        assert!(found.is_some(), "should analyze gelmediler as gel+me+di+ler");
+    }
 
-        // *gel+m+di (Person before Tense - INVALID)
-        assert!(!classifier.validate_sequence(&["m", "di"]));
+    #[test]
+    fn test_analyze_bare_root_is_ambiguous_across_categories() {
+        let fst = Morphotactics::new();
+        let analyses = fst.analyze("ev");
 
-        // *yap+dı+ma (Tense before Negation - INVALID)
-        assert!(!classifier.validate_sequence(&["dı", "ma"]));
+        assert!(analyses.iter().any(|a| a.category == "Noun" && a.morphemes.is_empty()));
+        assert!(analyses.iter().any(|a| a.category == "Verb" && a.morphemes.is_empty()));
     }
 
     #[test]
-    fn test_mixed_paradigm_rejection() {
-        let classifier = MorphotacticClassifier::new();
-
-        // *kitap+lar+di (Nominal Plural + Verbal Tense - INVALID)
-        assert!(!classifier.validate_sequence(&["lar", "di"]));
+    fn test_analyze_unparseable_word_returns_empty() {
+        let fst = Morphotactics::new();
+        assert!(fst.analyze("xyz").is_empty());
+    }
 
-        // *gel+di+da (Verbal Tense + Nominal Case - INVALID)
-        assert!(!classifier.validate_sequence(&["di", "da"]));
+    #[test]
+    fn test_generate_nominal_examples() {
+        let fst = Morphotactics::new();
+
+        let kitap = fst
+            .generate(
+                "kitap",
+                &NominalSpec { number: Number::Pl, possessor: Some(Person::P1Sg), case: Case::Loc },
+                &no_attrs(),
+            )
+            .expect("kitap+lar+ım+da should generate");
+        assert_eq!(kitap, "kitaplarımda");
+
+        let goz = fst
+            .generate(
+                "göz",
+                &NominalSpec { number: Number::Pl, possessor: Some(Person::P2Pl), case: Case::Abl },
+                &no_attrs(),
+            )
+            .expect("göz+ler+iniz+den should generate");
+        assert_eq!(goz, "gözlerinizden");
     }
 
     #[test]
-    fn test_unknown_suffix_permissive() {
-        let classifier = MorphotacticClassifier::new();
+    fn test_generate_bare_nominative() {
+        let fst = Morphotactics::new();
+        let ev = fst
+            .generate(
+                "ev",
+                &NominalSpec { number: Number::Sg, possessor: None, case: Case::Nom },
+                &no_attrs(),
+            )
+            .expect("bare root should generate");
+        assert_eq!(ev, "ev");
+    }
 
-        // Unknown suffixes are allowed (permissive mode)
-        assert!(classifier.validate_sequence(&["xyz"]));
-        assert!(classifier.validate_sequence(&["lar", "xyz"]));
-        assert!(classifier.validate_sequence(&["xyz", "lar"]));
+    #[test]
+    fn test_generate_verbal_example() {
+        let fst = Morphotactics::new();
+        let geldim = fst
+            .generate_verbal(
+                "gel",
+                &VerbalSpec {
+                    voice: Voice::Active,
+                    negation: false,
+                    tense_aspect: TenseAspect::Past,
+                    person: Person::P1Sg,
+                },
+                &no_attrs(),
+            )
+            .expect("gel+di+m should generate");
+        assert_eq!(geldim, "geldim");
     }
 
     #[test]
-    fn test_real_world_examples() {
-        let classifier = MorphotacticClassifier::new();
-
-        // Valid Turkish morphology
-        assert!(classifier.validate_sequence(&["lar", "ım", "da"])); // kitaplarımda
-        assert!(classifier.validate_sequence(&["ler", "imiz", "den"])); // evlerimizden
-        assert!(classifier.validate_sequence(&["di", "m"])); // geldim
-        assert!(classifier.validate_sequence(&["yor", "um"])); // geliyorum
-
-        // Invalid Turkish morphology
-        assert!(!classifier.validate_sequence(&["da", "lar"])); // *kitapdalar
-        assert!(!classifier.validate_sequence(&["m", "di"])); // *gelimdi (nonsense)
+    fn test_generate_rejects_paths_the_graph_has_no_edge_for() {
+        let fst = Morphotactics::new();
+        // A root with ImplicitPlural (e.g. a mass noun already plural in
+        // form) has no path to an explicit Number::Pl marking, the same
+        // condition `validate_sequence` gates the "lar"/"ler" edge on.
+        let mut attrs = HashSet::new();
+        attrs.insert(RootAttribute::ImplicitPlural);
+        let result = fst.generate(
+            "kitap",
+            &NominalSpec { number: Number::Pl, possessor: Some(Person::P1Sg), case: Case::Nom },
+            &attrs,
+        );
+        assert!(result.is_err());
     }
 }
@@ -0,0 +1,159 @@
+//! Phonetic hashing for fuzzy Turkish lemma lookup.
+//!
+//! A coarse, Soundex/eudex-style encoding: the first letter is kept
+//! verbatim, non-initial vowels are dropped, and consonants are bucketed
+//! into near-homophonous classes (c/ç, s/ş, z/j, g/ğ, k/q, t/d, etc.) so
+//! that spelling variants and typos still hash close together.
+
+use std::collections::HashMap;
+
+/// Map a consonant to its phonetic class, collapsing graphemes that are
+/// near-homophonous in Turkish (voicing pairs, palatalized pairs, and the
+/// /k/-/q/, /c/-/ç/ orthographic variants seen in loanwords/typos).
+fn consonant_class(c: char) -> Option<u8> {
+    match c.to_ascii_lowercase() {
+        'c' | 'ç' => Some(1),
+        's' | 'ş' => Some(2),
+        'z' | 'j' => Some(3),
+        'g' | 'ğ' => Some(4),
+        'k' | 'q' => Some(5),
+        't' | 'd' => Some(6),
+        'b' | 'p' => Some(7),
+        'f' | 'v' => Some(8),
+        'l' => Some(9),
+        'r' => Some(10),
+        'm' => Some(11),
+        'n' => Some(12),
+        'y' => Some(13),
+        'h' => Some(14),
+        'w' | 'x' => Some(15),
+        _ => None,
+    }
+}
+
+fn is_vowel(c: char) -> bool {
+    matches!(
+        c.to_ascii_lowercase(),
+        'a' | 'e' | 'ı' | 'i' | 'o' | 'ö' | 'u' | 'ü'
+    )
+}
+
+/// Pack a coarse phonetic encoding of `word` into a `u64`: the first
+/// character is kept verbatim in the highest-order byte, then up to 7
+/// further bytes hold per-consonant phonetic class codes, skipping every
+/// non-initial vowel the way Soundex does. Words shorter than 8 encoded
+/// letters are zero-padded in the low-order bytes.
+pub fn phonetic_hash(word: &str) -> u64 {
+    let mut chars = word.chars();
+    let mut bytes = [0u8; 8];
+
+    if let Some(first) = chars.next() {
+        bytes[0] = first.to_ascii_lowercase() as u8;
+    }
+
+    let mut i = 1;
+    for c in chars {
+        if i >= bytes.len() {
+            break;
+        }
+        if is_vowel(c) {
+            continue;
+        }
+        if let Some(class) = consonant_class(c) {
+            bytes[i] = class;
+            i += 1;
+        }
+    }
+
+    u64::from_be_bytes(bytes)
+}
+
+/// Byte-position-weighted Hamming distance between two phonetic hashes: a
+/// mismatch in an earlier (higher-order) byte costs more than a mismatch
+/// in a later one, since divergence in the word stem should cost more
+/// than divergence in the tail.
+pub fn phonetic_distance(a: u64, b: u64) -> u32 {
+    let a_bytes = a.to_be_bytes();
+    let b_bytes = b.to_be_bytes();
+
+    a_bytes
+        .iter()
+        .zip(b_bytes.iter())
+        .enumerate()
+        .filter(|(_, (x, y))| x != y)
+        .map(|(i, _)| (a_bytes.len() - i) as u32)
+        .sum()
+}
+
+/// Scan `dict`'s keys for the one whose phonetic hash is closest to
+/// `word`'s, returning its lemma if the best distance is within
+/// `max_distance`. Used as a fallback when exact lookup misses, to
+/// recover from misspellings and orthographic variants.
+pub fn lookup_lemma_fuzzy<'a>(
+    word: &str,
+    dict: &HashMap<&'static str, &'a str>,
+    max_distance: u32,
+) -> Option<&'a str> {
+    let target = phonetic_hash(word);
+
+    dict.keys()
+        .map(|&key| (key, phonetic_distance(target, phonetic_hash(key))))
+        .filter(|&(_, dist)| dist <= max_distance)
+        .min_by_key(|&(_, dist)| dist)
+        .map(|(key, _)| dict[key])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hash_keeps_first_letter_verbatim() {
+        let h1 = phonetic_hash("kitap");
+        let h2 = phonetic_hash("gitap");
+        assert_ne!(h1.to_be_bytes()[0], h2.to_be_bytes()[0]);
+    }
+
+    #[test]
+    fn test_hash_drops_non_initial_vowels() {
+        assert_eq!(phonetic_hash("kitap"), phonetic_hash("kıtap"));
+        assert_eq!(phonetic_hash("kitap"), phonetic_hash("kitaap"));
+    }
+
+    #[test]
+    fn test_hash_collapses_near_homophones() {
+        // The collapsed classes only apply past the verbatim first letter.
+        assert_eq!(phonetic_hash("kaçtı"), phonetic_hash("kactı"));
+        assert_eq!(phonetic_hash("gelecek"), phonetic_hash("geleçek"));
+    }
+
+    #[test]
+    fn test_distance_zero_for_identical_hash() {
+        assert_eq!(phonetic_distance(phonetic_hash("kitap"), phonetic_hash("kitap")), 0);
+    }
+
+    #[test]
+    fn test_distance_weighs_early_bytes_more() {
+        // Mismatch in the stem (early bytes) vs. mismatch only in the tail.
+        let stem_mismatch = phonetic_distance(phonetic_hash("kitap"), phonetic_hash("gitap"));
+        let tail_mismatch = phonetic_distance(phonetic_hash("kitaplarrr"), phonetic_hash("kitaplarrx"));
+        assert!(stem_mismatch > tail_mismatch);
+    }
+
+    #[test]
+    fn test_lookup_lemma_fuzzy_recovers_misspelling() {
+        let mut dict = HashMap::new();
+        dict.insert("kitaplar", "kitap");
+        dict.insert("geliyorum", "gel");
+
+        assert_eq!(lookup_lemma_fuzzy("kıtaplar", &dict, 4), Some("kitap"));
+    }
+
+    #[test]
+    fn test_lookup_lemma_fuzzy_respects_max_distance() {
+        let mut dict = HashMap::new();
+        dict.insert("kitaplar", "kitap");
+
+        assert_eq!(lookup_lemma_fuzzy("arabalar", &dict, 1), None);
+    }
+}
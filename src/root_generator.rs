@@ -0,0 +1,294 @@
+//! Turkish pseudoword generator, inverting the phonotactic knowledge in
+//! `root_validator`: instead of judging a candidate root, `RootGenerator`
+//! samples one from scratch by stringing together V/VC/CV/CVC syllables
+//! whose vowels obey harmony and whose consonants avoid the same invalid
+//! final clusters `RootValidator` rejects. This is useful for data
+//! augmentation and for stress-testing the validator - every word this
+//! generator produces is checked against `RootValidator::is_valid_root` in
+//! lenient mode before it's returned, so the two stay mutually consistent
+//! by construction; a generated word that fails validation indicates a
+//! validator bug, not a generator one.
+
+use crate::root_validator::{RootValidator, VOICELESS_STOPS};
+use crate::vowel_harmony::VowelClass;
+
+/// Every Turkish consonant, minus `ğ`, which never stands alone as an
+/// onset or coda.
+const CONSONANTS: &[char] = &[
+    'b', 'c', 'ç', 'd', 'f', 'g', 'h', 'j', 'k', 'l', 'm', 'n', 'p', 'r', 's', 'ş', 't', 'v', 'y',
+    'z',
+];
+
+#[derive(Debug, Clone, Copy)]
+enum SyllableTemplate {
+    V,
+    Cv,
+    Vc,
+    Cvc,
+}
+
+/// Templates paired with a sampling weight - CV and CVC are by far the
+/// most common Turkish syllable shapes, so they're weighted heavier than
+/// the vowel-initial V/VC shapes.
+const TEMPLATES: &[(SyllableTemplate, u32)] = &[
+    (SyllableTemplate::Cv, 4),
+    (SyllableTemplate::Cvc, 4),
+    (SyllableTemplate::V, 1),
+    (SyllableTemplate::Vc, 1),
+];
+
+/// A minimal deterministic PRNG (SplitMix64) so `RootGenerator` is
+/// seedable without pulling in an external `rand` dependency.
+struct Rng(u64);
+
+impl Rng {
+    fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    fn gen_range(&mut self, n: u32) -> u32 {
+        (self.next_u64() % n as u64) as u32
+    }
+}
+
+fn pick_template(rng: &mut Rng) -> SyllableTemplate {
+    let total: u32 = TEMPLATES.iter().map(|(_, w)| w).sum();
+    let mut roll = rng.gen_range(total);
+    for &(template, weight) in TEMPLATES {
+        if roll < weight {
+            return template;
+        }
+        roll -= weight;
+    }
+    unreachable!("weights cover the full range by construction")
+}
+
+fn pick_consonant(rng: &mut Rng, exclude_voiceless_stops: bool) -> char {
+    loop {
+        let c = CONSONANTS[rng.gen_range(CONSONANTS.len() as u32) as usize];
+        if !exclude_voiceless_stops || !VOICELESS_STOPS.contains(&c) {
+            return c;
+        }
+    }
+}
+
+/// The low/high vowel letter for a given `VowelClass`.
+fn vowel_for(class: VowelClass, high: bool) -> char {
+    match (class, high) {
+        (VowelClass::BackUnrounded, false) => 'a',
+        (VowelClass::BackUnrounded, true) => 'ı',
+        (VowelClass::BackRounded, false) => 'o',
+        (VowelClass::BackRounded, true) => 'u',
+        (VowelClass::FrontUnrounded, false) => 'e',
+        (VowelClass::FrontUnrounded, true) => 'i',
+        (VowelClass::FrontRounded, false) => 'ö',
+        (VowelClass::FrontRounded, true) => 'ü',
+    }
+}
+
+/// Pick the first vowel of a word freely among all eight vowels.
+fn pick_first_vowel(rng: &mut Rng) -> (char, VowelClass, bool) {
+    let classes = [
+        VowelClass::BackUnrounded,
+        VowelClass::BackRounded,
+        VowelClass::FrontUnrounded,
+        VowelClass::FrontRounded,
+    ];
+    let class = classes[rng.gen_range(4) as usize];
+    let high = rng.gen_range(2) == 0;
+    (vowel_for(class, high), class, high)
+}
+
+/// Pick a vowel harmonizing with the previous one: matching backness
+/// always, and - when the previous vowel was rounded - restricted to a
+/// high rounded vowel or a low unrounded one, mirroring the rule
+/// `RootValidator::check_vowel_harmony` enforces.
+fn pick_next_vowel(rng: &mut Rng, prev_class: VowelClass) -> (char, VowelClass, bool) {
+    let front = prev_class.is_front();
+    if prev_class.is_rounded() {
+        if rng.gen_range(2) == 0 {
+            let class = if front {
+                VowelClass::FrontRounded
+            } else {
+                VowelClass::BackRounded
+            };
+            (vowel_for(class, true), class, true)
+        } else {
+            let class = if front {
+                VowelClass::FrontUnrounded
+            } else {
+                VowelClass::BackUnrounded
+            };
+            (vowel_for(class, false), class, false)
+        }
+    } else {
+        let class = if rng.gen_range(2) == 0 {
+            if front {
+                VowelClass::FrontUnrounded
+            } else {
+                VowelClass::BackUnrounded
+            }
+        } else if front {
+            VowelClass::FrontRounded
+        } else {
+            VowelClass::BackRounded
+        };
+        let high = rng.gen_range(2) == 0;
+        (vowel_for(class, high), class, high)
+    }
+}
+
+/// Synthesizes plausible Turkish pseudoroots from syllable templates and
+/// vowel harmony, for data augmentation and validator stress-testing.
+pub struct RootGenerator {
+    rng: Rng,
+    min_syllables: usize,
+    max_syllables: usize,
+}
+
+impl RootGenerator {
+    /// A generator producing 1-3 syllable words from the given seed.
+    pub fn new(seed: u64) -> Self {
+        Self::with_syllable_range(seed, 1, 3)
+    }
+
+    /// A generator constrained to `min_syllables..=max_syllables`.
+    pub fn with_syllable_range(seed: u64, min_syllables: usize, max_syllables: usize) -> Self {
+        Self {
+            rng: Rng::new(seed),
+            min_syllables: min_syllables.max(1),
+            max_syllables: max_syllables.max(min_syllables.max(1)),
+        }
+    }
+
+    /// Build one candidate word, without validating it.
+    fn sample_candidate(&mut self) -> String {
+        let syllable_count = self.min_syllables
+            + self.rng.gen_range((self.max_syllables - self.min_syllables + 1) as u32) as usize;
+
+        let mut word = String::new();
+        let mut prev_vowel_class: Option<VowelClass> = None;
+
+        for syllable_idx in 0..syllable_count {
+            let is_last_syllable = syllable_idx == syllable_count - 1;
+            let template = pick_template(&mut self.rng);
+
+            let (vowel, vowel_class, _) = match prev_vowel_class {
+                None => pick_first_vowel(&mut self.rng),
+                Some(class) => pick_next_vowel(&mut self.rng, class),
+            };
+            prev_vowel_class = Some(vowel_class);
+
+            // A single-syllable word never ends in a bare voiceless stop.
+            let avoid_voiceless_coda =
+                is_last_syllable && syllable_count == 1 && matches!(template, SyllableTemplate::Vc | SyllableTemplate::Cvc);
+
+            match template {
+                SyllableTemplate::V => word.push(vowel),
+                SyllableTemplate::Cv => {
+                    word.push(pick_consonant(&mut self.rng, false));
+                    word.push(vowel);
+                }
+                SyllableTemplate::Vc => {
+                    word.push(vowel);
+                    word.push(pick_consonant(&mut self.rng, avoid_voiceless_coda));
+                }
+                SyllableTemplate::Cvc => {
+                    word.push(pick_consonant(&mut self.rng, false));
+                    word.push(vowel);
+                    word.push(pick_consonant(&mut self.rng, avoid_voiceless_coda));
+                }
+            }
+        }
+
+        word
+    }
+
+    /// Generate one Turkish pseudoroot that is guaranteed to satisfy
+    /// `RootValidator::is_valid_root` in lenient mode, resampling
+    /// candidates that happen to land on a rejected shape (e.g. a bare
+    /// one-letter "V" word below the minimum root length, or a word
+    /// ending in one of the validator's bound stems).
+    pub fn generate(&mut self) -> String {
+        let validator = RootValidator::default();
+        for _ in 0..1000 {
+            let candidate = self.sample_candidate();
+            if validator.is_valid_root(&candidate) {
+                return candidate;
+            }
+        }
+        // Exhausting the resampling budget would itself indicate a
+        // validator/generator mismatch worth surfacing loudly.
+        panic!("RootGenerator could not produce a valid root after 1000 attempts");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::root_validator::INVALID_FINAL_CLUSTERS;
+
+    #[test]
+    fn test_seeded_generator_is_deterministic() {
+        let mut a = RootGenerator::new(42);
+        let mut b = RootGenerator::new(42);
+        let words_a: Vec<String> = (0..20).map(|_| a.generate()).collect();
+        let words_b: Vec<String> = (0..20).map(|_| b.generate()).collect();
+        assert_eq!(words_a, words_b);
+    }
+
+    #[test]
+    fn test_generated_words_pass_validator() {
+        let validator = RootValidator::default();
+        let mut generator = RootGenerator::new(7);
+        for _ in 0..200 {
+            let word = generator.generate();
+            assert!(validator.is_valid_root(&word), "generated invalid root: {word}");
+        }
+    }
+
+    #[test]
+    fn test_generated_words_respect_syllable_range() {
+        // Exactly one vowel per syllable by construction, so vowel count
+        // is a direct proxy for syllable count.
+        let mut generator = RootGenerator::with_syllable_range(99, 2, 2);
+        for _ in 0..50 {
+            let word = generator.generate();
+            let vowel_count = word
+                .chars()
+                .filter(|&c| crate::vowel_harmony::get_vowel_class(c).is_some())
+                .count();
+            assert_eq!(vowel_count, 2, "word {word} has the wrong syllable count");
+        }
+    }
+
+    #[test]
+    fn test_single_syllable_words_never_end_in_voiceless_stop() {
+        let mut generator = RootGenerator::with_syllable_range(123, 1, 1);
+        for _ in 0..200 {
+            let word = generator.generate();
+            let last = word.chars().last().unwrap();
+            assert!(!VOICELESS_STOPS.contains(&last), "word {word} ends in a voiceless stop");
+        }
+    }
+
+    #[test]
+    fn test_never_produces_invalid_final_cluster() {
+        let mut generator = RootGenerator::new(2024);
+        for _ in 0..200 {
+            let word = generator.generate();
+            let lower = word.to_lowercase();
+            for cluster in INVALID_FINAL_CLUSTERS {
+                assert!(!lower.ends_with(cluster), "word {word} ends in invalid cluster {cluster}");
+            }
+        }
+    }
+}
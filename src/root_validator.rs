@@ -6,19 +6,21 @@
 use std::collections::HashSet;
 use std::sync::OnceLock;
 
+use crate::vowel_harmony::{get_vowel_class, VowelClass};
+
 /// Turkish vowels (both lowercase and uppercase)
 const TURKISH_VOWELS: &[char] = &[
     'a', 'e', 'ı', 'i', 'o', 'ö', 'u', 'ü', 'A', 'E', 'I', 'İ', 'O', 'Ö', 'U', 'Ü',
 ];
 
 /// Turkish sonorant consonants (can end words naturally)
-const SONORANT_CONSONANTS: &[char] = &['l', 'r', 'n', 'm', 'y', 'L', 'R', 'N', 'M', 'Y'];
+pub(crate) const SONORANT_CONSONANTS: &[char] = &['l', 'r', 'n', 'm', 'y', 'L', 'R', 'N', 'M', 'Y'];
 
 /// Turkish voiceless stops (words typically don't end in these without specific patterns)
-const VOICELESS_STOPS: &[char] = &['p', 'ç', 't', 'k', 'P', 'Ç', 'T', 'K'];
+pub(crate) const VOICELESS_STOPS: &[char] = &['p', 'ç', 't', 'k', 'P', 'Ç', 'T', 'K'];
 
 /// Impossible Turkish consonant clusters at word end
-const INVALID_FINAL_CLUSTERS: &[&str] = &[
+pub(crate) const INVALID_FINAL_CLUSTERS: &[&str] = &[
     "çk", "çp", "çt", "ğk", "ğp", "ğt", "kb", "kc", "kç", "kg", "kğ", "kj", "pb", "pc", "pç", "pg",
     "pğ", "pj", "tb", "tc", "tç", "tg", "tğ", "tj", "nd", "nt", "nk", "ng",
 ];
@@ -53,6 +55,101 @@ pub fn get_valid_roots() -> &'static HashSet<String> {
     })
 }
 
+/// The degree to which a word's vowels satisfy Turkish vowel harmony.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HarmonyResult {
+    /// Every vowel agrees in backness, and the restricted rounding
+    /// harmony holds between every pair too.
+    Harmonic,
+    /// Harmony breaks somewhere in the word's own body - consistent with
+    /// a native-looking loanword (e.g. "kitap"), not with a stripping
+    /// artifact. Still accepted, just flagged.
+    Disharmonic,
+    /// Every vowel up to the last agrees with its neighbors, and only the
+    /// final vowel breaks the pattern - the signature of a suffix-initial
+    /// vowel a stripper left behind on the root. Needs at least three
+    /// vowels; a two-vowel word whose vowels disagree is `Disharmonic`.
+    Broken,
+}
+
+/// High (close) Turkish vowels: ı, i, u, ü. The low (open) vowels are a,
+/// e, o, ö. This is orthogonal to backness/rounding and only needed for
+/// the restricted rounding-harmony rule in `RootValidator::check_vowel_harmony`.
+fn is_high_vowel(c: char) -> bool {
+    matches!(c.to_lowercase().next(), Some('ı' | 'i' | 'u' | 'ü'))
+}
+
+/// A single Turkish syllable: its (at most one-consonant) onset, vowel
+/// nucleus, and (at most two-consonant) coda.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Syllable {
+    pub onset: String,
+    pub nucleus: char,
+    pub coda: String,
+}
+
+/// Parse `chars` into Turkish syllables by maximal-coda-free resolution:
+/// scanning vowel by vowel, an intervocalic consonant run of 1 starts the
+/// next syllable (CV.CV), a run of 2 splits one-one (VC.CV), and a run of
+/// 3 splits two-one (VCC.CV) for loanword clusters. Returns `None` if the
+/// word has no vowel, an onset or coda longer than these templates allow,
+/// or a word-final coda matching `INVALID_FINAL_CLUSTERS` - i.e. whenever
+/// the string can't be fully consumed as legal V/VC/CV/CVC/CVCC syllables.
+fn syllabify(chars: &[char]) -> Option<Vec<Syllable>> {
+    let vowel_positions: Vec<usize> = chars
+        .iter()
+        .enumerate()
+        .filter_map(|(i, c)| TURKISH_VOWELS.contains(c).then_some(i))
+        .collect();
+
+    let &first_vowel = vowel_positions.first()?;
+    // No template has a multi-consonant onset.
+    if first_vowel > 1 {
+        return None;
+    }
+
+    let mut syllables = Vec::with_capacity(vowel_positions.len());
+    let mut onset: String = chars[..first_vowel].iter().collect();
+
+    for (idx, &vowel) in vowel_positions.iter().enumerate() {
+        let nucleus = chars[vowel];
+        let next_vowel = vowel_positions.get(idx + 1).copied();
+
+        let (coda, next_onset) = match next_vowel {
+            Some(next) => {
+                let run_len = next - vowel - 1;
+                match run_len {
+                    0 => (String::new(), String::new()),
+                    1..=3 => {
+                        let coda: String = chars[vowel + 1..next - 1].iter().collect();
+                        let next_onset: String = chars[next - 1..next].iter().collect();
+                        (coda, next_onset)
+                    }
+                    _ => return None,
+                }
+            }
+            None => {
+                let tail: Vec<char> = chars[vowel + 1..].to_vec();
+                if tail.len() > 2 {
+                    return None;
+                }
+                if tail.len() == 2 {
+                    let cluster: String = tail.iter().collect::<String>().to_lowercase();
+                    if INVALID_FINAL_CLUSTERS.contains(&cluster.as_str()) {
+                        return None;
+                    }
+                }
+                (tail.into_iter().collect(), String::new())
+            }
+        };
+
+        syllables.push(Syllable { onset, nucleus, coda });
+        onset = next_onset;
+    }
+
+    Some(syllables)
+}
+
 /// Root validity checker for Turkish morphology
 pub struct RootValidator {
     /// Minimum acceptable root length (characters)
@@ -79,6 +176,45 @@ impl RootValidator {
         }
     }
 
+    /// Classify `word`'s vowel-harmony pattern on two axes: backness (all
+    /// vowels must agree) and the restricted rounding harmony (a rounded
+    /// vowel may only be followed by a high rounded vowel or a low
+    /// unrounded vowel). See `HarmonyResult` for how the result is used to
+    /// tell a genuine loanword apart from an over-stripped fragment.
+    pub fn check_vowel_harmony(&self, word: &str) -> HarmonyResult {
+        let vowels: Vec<char> = word.chars().filter(|&c| get_vowel_class(c).is_some()).collect();
+        if vowels.len() < 2 {
+            return HarmonyResult::Harmonic;
+        }
+
+        let classes: Vec<VowelClass> = vowels.iter().map(|&c| get_vowel_class(c).unwrap()).collect();
+
+        let pair_violates = |i: usize| -> bool {
+            let (prev, next) = (classes[i], classes[i + 1]);
+            if prev.is_front() != next.is_front() {
+                return true;
+            }
+            if prev.is_rounded() {
+                let next_ok = (next.is_rounded() && is_high_vowel(vowels[i + 1]))
+                    || (!next.is_rounded() && !is_high_vowel(vowels[i + 1]));
+                if !next_ok {
+                    return true;
+                }
+            }
+            false
+        };
+
+        let violations: Vec<usize> = (0..vowels.len() - 1).filter(|&i| pair_violates(i)).collect();
+
+        if violations.is_empty() {
+            HarmonyResult::Harmonic
+        } else if vowels.len() >= 3 && violations == [vowels.len() - 2] {
+            HarmonyResult::Broken
+        } else {
+            HarmonyResult::Disharmonic
+        }
+    }
+
     /// Check if a candidate root is valid
     pub fn is_valid_root(&self, candidate: &str) -> bool {
         // 1. Minimum length check
@@ -125,6 +261,14 @@ impl RootValidator {
             }
         }
 
+        // A root whose harmony is only broken at its very last vowel is
+        // almost always a stray suffix vowel left behind by over-stripping,
+        // not a real root - a genuinely disharmonic loanword breaks
+        // harmony somewhere in its own body instead.
+        if self.check_vowel_harmony(&word_lower) == HarmonyResult::Broken {
+            return false;
+        }
+
         // Check if last char is a vowel - always valid
         let last_char = lower_chars.last().unwrap();
         if TURKISH_VOWELS.contains(last_char) {
@@ -138,30 +282,22 @@ impl RootValidator {
 
         // Voiceless stops at word end are valid in Turkish
         if VOICELESS_STOPS.contains(last_char) {
-            return lower_chars.len() >= 3 && self.has_valid_syllable_structure(&lower_chars);
+            return self.has_valid_syllable_structure(&lower_chars);
         }
 
         true
     }
 
-    /// Check if the word has a valid Turkish syllable structure
+    /// Check if the word has a valid Turkish syllable structure: it parses
+    /// cleanly into legal V/VC/CV/CVC/CVCC syllables with `syllabify`,
+    /// rather than merely falling inside a plausible vowel-to-consonant
+    /// ratio.
     fn has_valid_syllable_structure(&self, chars: &[char]) -> bool {
         if chars.len() < 2 {
             return false;
         }
 
-        let vowel_count = chars.iter().filter(|c| TURKISH_VOWELS.contains(c)).count();
-
-        if vowel_count == 0 {
-            return false;
-        }
-
-        if chars.len() <= 3 {
-            return vowel_count >= 1;
-        }
-
-        let vowel_ratio = vowel_count as f32 / chars.len() as f32;
-        vowel_ratio >= 0.2 && vowel_ratio <= 0.7
+        syllabify(chars).is_some()
     }
 }
 
@@ -226,4 +362,90 @@ mod tests {
         assert!(roots.contains("gel"));
         assert!(roots.contains("git"));
     }
+
+    #[test]
+    fn test_syllabify_single_consonant_and_final_coda() {
+        let chars: Vec<char> = "kitap".chars().collect();
+        let syllables = syllabify(&chars).unwrap();
+        assert_eq!(
+            syllables,
+            vec![
+                Syllable { onset: "k".to_string(), nucleus: 'i', coda: "".to_string() },
+                Syllable { onset: "t".to_string(), nucleus: 'a', coda: "p".to_string() },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_syllabify_three_consonant_cluster_splits_two_one() {
+        // "türkçe": the rkç run between ü and e splits VCC.CV - "rk" closes
+        // the first syllable, "ç" opens the second.
+        let chars: Vec<char> = "türkçe".chars().collect();
+        let syllables = syllabify(&chars).unwrap();
+        assert_eq!(
+            syllables,
+            vec![
+                Syllable { onset: "t".to_string(), nucleus: 'ü', coda: "rk".to_string() },
+                Syllable { onset: "ç".to_string(), nucleus: 'e', coda: "".to_string() },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_syllabify_rejects_multi_consonant_onset() {
+        // No Turkish syllable template has a CCV onset.
+        let chars: Vec<char> = "stan".chars().collect();
+        assert!(syllabify(&chars).is_none());
+    }
+
+    #[test]
+    fn test_syllabify_rejects_four_consonant_intervocalic_run() {
+        let chars: Vec<char> = "arstla".chars().collect();
+        assert!(syllabify(&chars).is_none());
+    }
+
+    #[test]
+    fn test_syllabify_rejects_invalid_final_cluster() {
+        let chars: Vec<char> = "kitaçk".chars().collect();
+        assert!(syllabify(&chars).is_none());
+    }
+
+    #[test]
+    fn test_check_vowel_harmony_harmonic_word() {
+        let validator = RootValidator::default();
+        assert_eq!(validator.check_vowel_harmony("masalar"), HarmonyResult::Harmonic);
+        assert_eq!(validator.check_vowel_harmony("evler"), HarmonyResult::Harmonic);
+    }
+
+    #[test]
+    fn test_check_vowel_harmony_disharmonic_loanword() {
+        // A front vowel followed by a back vowel within the root's own
+        // body, not localized to the last vowel - a loanword, not a
+        // stripping artifact.
+        let validator = RootValidator::default();
+        assert_eq!(validator.check_vowel_harmony("kitap"), HarmonyResult::Disharmonic);
+    }
+
+    #[test]
+    fn test_check_vowel_harmony_broken_suggests_leftover_suffix_vowel() {
+        // "o" and "u" harmonize (back, rounded-high-follows-rounded), but
+        // the trailing "e" alone breaks backness - exactly the shape left
+        // behind when a suffix vowel wasn't fully stripped off the root.
+        let validator = RootValidator::default();
+        assert_eq!(validator.check_vowel_harmony("yolumse"), HarmonyResult::Broken);
+    }
+
+    #[test]
+    fn test_broken_harmony_rejected_by_is_valid_root() {
+        let validator = RootValidator::default();
+        assert!(!validator.is_valid_root("yolumse"));
+    }
+
+    #[test]
+    fn test_over_stripped_fragment_rejected_by_syllable_structure() {
+        // A fragment retaining a stray suffix-initial consonant cluster
+        // should no longer slip past a lax vowel-ratio check.
+        let validator = RootValidator::default();
+        assert!(!validator.is_valid_root("stan"));
+    }
 }
@@ -0,0 +1,222 @@
+//! Harmony- and mutation-aware suffix stripping: the inverse of
+//! `vowel_harmony::realize`.
+//!
+//! Suffixes are represented the same way `realize` produces them, as
+//! archiphoneme templates (`"l{A}r"` for the plural, `"{I}n"` for the
+//! genitive/2sg-possessive, ...). A suffix only strips when its surface
+//! form, resolved against the *remaining* stem, actually matches the end
+//! of the word and harmonizes with that stem — so stripping never
+//! outruns what `realize` would have generated in the first place. After
+//! a vowel-initial suffix strips, the newly exposed stem-final consonant
+//! is un-softened (b→p, c→ç, d→t, ğ→k), reversing the voicing Turkish
+//! applies between vowels. The bare Acc/P3sg vowel suffix is additionally
+//! gated on `root_validator::RootValidator::check_vowel_harmony`, since a
+//! bare high vowel is also just how plenty of underived nouns end
+//! ("kapı", "kutu") — a word that's already internally harmonic end to
+//! end reads as a complete root, not a root plus a tacked-on suffix.
+
+use crate::root_validator::{HarmonyResult, RootValidator};
+use crate::vowel_harmony::{check_vowel_harmony, get_vowel_class, realize};
+
+/// One stripped morpheme: its concrete surface form and a short gloss tag.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StrippedMorpheme {
+    pub surface: String,
+    pub gloss: &'static str,
+}
+
+/// A recognized suffix, tried in order against the current stem.
+struct SuffixRule {
+    /// Archiphoneme template, as consumed by `vowel_harmony::realize`.
+    template: &'static str,
+    gloss: &'static str,
+    /// A bare high vowel is both the Acc/P3sg suffix and how many
+    /// underived nouns simply end ("kapı", "kutu", "kedi"), so this rule
+    /// only strips when the word's own vowel harmony isn't already fully
+    /// self-consistent - every other suffix here is distinctive enough on
+    /// its own not to need the harmony check.
+    harmony_gated: bool,
+}
+
+const SUFFIX_RULES: &[SuffixRule] = &[
+    SuffixRule { template: "l{A}r", gloss: "Plural", harmony_gated: false },
+    SuffixRule { template: "d{A}n", gloss: "Ablative", harmony_gated: false },
+    SuffixRule { template: "d{A}", gloss: "Locative", harmony_gated: false },
+    SuffixRule { template: "{I}n", gloss: "Gen/P2sg", harmony_gated: false },
+    SuffixRule { template: "{I}m", gloss: "P1sg", harmony_gated: false },
+    SuffixRule { template: "{I}", gloss: "Acc/P3sg", harmony_gated: true },
+];
+
+/// Minimum remaining root length (in characters), to stop stripping
+/// before a short root is chopped down to nothing recognizable.
+const MIN_STEM_LENGTH: usize = 2;
+
+/// Every archiphoneme template here resolves exactly one character per
+/// token (literal or `{...}` placeholder), so a template's surface length
+/// is fixed regardless of which harmony variant it resolves to.
+fn template_len(template: &str) -> usize {
+    let mut len = 0;
+    let mut chars = template.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == '{' {
+            for c2 in chars.by_ref() {
+                if c2 == '}' {
+                    break;
+                }
+            }
+        }
+        len += 1;
+    }
+    len
+}
+
+/// Reverse intervocalic consonant softening at a newly exposed stem
+/// boundary (b→p, c→ç, d→t, ğ→k).
+fn un_soften(c: char) -> char {
+    match c {
+        'b' => 'p',
+        'c' => 'ç',
+        'd' => 't',
+        'ğ' => 'k',
+        other => other,
+    }
+}
+
+/// Try every suffix rule against `stem`, returning the root and stripped
+/// morpheme for the first one whose harmony-resolved surface form matches
+/// the end of `stem`.
+fn try_strip_one(stem: &str) -> Option<(String, StrippedMorpheme)> {
+    let chars: Vec<char> = stem.chars().collect();
+
+    for rule in SUFFIX_RULES {
+        let len = template_len(rule.template);
+        if chars.len() < len + MIN_STEM_LENGTH {
+            continue;
+        }
+        // A word whose vowels harmonize end to end, including the vowel
+        // this rule is about to strip, behaves like a single coherent
+        // root rather than a root with a suffix vowel appended - only a
+        // leftover internal break (Broken) or loanword-shaped disharmony
+        // (Disharmonic) is consistent with that final vowel being tacked
+        // on separately.
+        if rule.harmony_gated
+            && RootValidator::default().check_vowel_harmony(stem) == HarmonyResult::Harmonic
+        {
+            continue;
+        }
+
+        let split = chars.len() - len;
+        let candidate_root: String = chars[..split].iter().collect();
+        let expected_surface = realize(rule.template, &candidate_root);
+
+        if !stem.ends_with(&expected_surface) {
+            continue;
+        }
+        if !check_vowel_harmony(&candidate_root, &expected_surface) {
+            continue;
+        }
+
+        let mut root = candidate_root;
+        let vowel_initial = expected_surface
+            .chars()
+            .next()
+            .is_some_and(|c| get_vowel_class(c).is_some());
+        if vowel_initial {
+            if let Some(last) = root.pop() {
+                root.push(un_soften(last));
+            }
+        }
+
+        return Some((
+            root,
+            StrippedMorpheme { surface: expected_surface, gloss: rule.gloss },
+        ));
+    }
+
+    None
+}
+
+/// Repeatedly strip the first recognized suffix from `word`, honoring
+/// vowel harmony at every step and un-softening the exposed stem boundary
+/// after a vowel-initial suffix, until no rule matches or the minimum
+/// stem length would be violated. Returns the final root and every
+/// morpheme stripped, in strip order.
+pub fn analyze_suffixes(word: &str) -> (String, Vec<StrippedMorpheme>) {
+    let mut stem = word.to_string();
+    let mut morphemes = Vec::new();
+
+    while let Some((next_stem, morpheme)) = try_strip_one(&stem) {
+        stem = next_stem;
+        morphemes.push(morpheme);
+    }
+
+    (stem, morphemes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_strips_possessive_and_unsoftens_stem_final_stop() {
+        let (root, morphemes) = analyze_suffixes("kitabı");
+        assert_eq!(root, "kitap");
+        assert_eq!(morphemes, vec![StrippedMorpheme { surface: "ı".to_string(), gloss: "Acc/P3sg" }]);
+    }
+
+    #[test]
+    fn test_strips_genitive_and_unsoftens_c_to_c_cedilla() {
+        let (root, morphemes) = analyze_suffixes("ağacın");
+        assert_eq!(root, "ağaç");
+        assert_eq!(morphemes, vec![StrippedMorpheme { surface: "ın".to_string(), gloss: "Gen/P2sg" }]);
+    }
+
+    #[test]
+    fn test_strips_plural_without_unsoftening_consonant_initial_suffix() {
+        let (root, morphemes) = analyze_suffixes("kitaplar");
+        assert_eq!(root, "kitap");
+        assert_eq!(morphemes, vec![StrippedMorpheme { surface: "lar".to_string(), gloss: "Plural" }]);
+    }
+
+    #[test]
+    fn test_strips_multiple_suffixes_in_order() {
+        let (root, morphemes) = analyze_suffixes("evlerden");
+        assert_eq!(root, "ev");
+        assert_eq!(
+            morphemes,
+            vec![
+                StrippedMorpheme { surface: "den".to_string(), gloss: "Ablative" },
+                StrippedMorpheme { surface: "ler".to_string(), gloss: "Plural" },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_rejects_harmony_violating_suffix() {
+        // "kitapler" would require a front suffix on a back-harmony
+        // root; no rule should match and the word is returned whole.
+        let (root, morphemes) = analyze_suffixes("kitapler");
+        assert_eq!(root, "kitapler");
+        assert!(morphemes.is_empty());
+    }
+
+    #[test]
+    fn test_min_stem_length_prevents_over_stripping() {
+        // "elin" could in principle strip "in" as Gen/P2sg down to "el",
+        // but no further: the 2-char minimum blocks stripping "el" itself.
+        let (root, morphemes) = analyze_suffixes("elin");
+        assert_eq!(root, "el");
+        assert_eq!(morphemes, vec![StrippedMorpheme { surface: "in".to_string(), gloss: "Gen/P2sg" }]);
+    }
+
+    #[test]
+    fn test_bare_nouns_ending_in_a_high_vowel_are_not_over_stripped() {
+        // "kapı" (door), "kutu" (box), and "kedi" (cat) are roots in their
+        // own right, not a consonant-final root plus Acc/P3sg "ı"/"u"/"i" -
+        // their internally harmonic vowels gate the Acc/P3sg rule off and
+        // leave them whole.
+        assert_eq!(analyze_suffixes("kapı"), ("kapı".to_string(), vec![]));
+        assert_eq!(analyze_suffixes("kutu"), ("kutu".to_string(), vec![]));
+        assert_eq!(analyze_suffixes("kedi"), ("kedi".to_string(), vec![]));
+    }
+}
@@ -0,0 +1,93 @@
+//! Turkish syllabification via onset/coda cluster resolution.
+//!
+//! Turkish syllables are built around a single vowel nucleus (V, VC, CV,
+//! CVC, and a handful of CVCC shapes): given a word's vowel positions, the
+//! consonant run between two vowels splits by maximal onset - a lone
+//! consonant attaches to the *following* syllable as its onset, while a
+//! run of several consonants closes the *previous* syllable with all but
+//! the last, which alone carries over as the next onset. Leading
+//! consonants before the first vowel (if any - a vowel-initial word has
+//! an onsetless first syllable) and trailing consonants after the last
+//! are the first syllable's onset and the word-final coda respectively,
+//! and don't need special-casing beyond the boundaries already computed
+//! between vowel pairs.
+
+use crate::vowel_harmony::get_vowel_class;
+
+/// Split `word` into its syllables.
+///
+/// A word with no vowels (e.g. an abbreviation) is returned as a single
+/// "syllable" unchanged, since there's no nucleus to anchor a split.
+pub fn syllabify(word: &str) -> Vec<String> {
+    let chars: Vec<char> = word.chars().collect();
+    let vowel_positions: Vec<usize> = chars
+        .iter()
+        .enumerate()
+        .filter_map(|(i, &c)| get_vowel_class(c).map(|_| i))
+        .collect();
+
+    if vowel_positions.is_empty() {
+        return vec![word.to_string()];
+    }
+
+    let boundaries: Vec<usize> = vowel_positions
+        .windows(2)
+        .map(|pair| {
+            let (vowel, next_vowel) = (pair[0], pair[1]);
+            let run_len = next_vowel - vowel - 1;
+            if run_len == 0 {
+                next_vowel
+            } else {
+                // The last consonant of the run is the next syllable's
+                // onset; any consonants before it close this syllable.
+                next_vowel - 1
+            }
+        })
+        .collect();
+
+    let mut syllables = Vec::with_capacity(boundaries.len() + 1);
+    let mut start = 0;
+    for boundary in boundaries {
+        syllables.push(chars[start..boundary].iter().collect());
+        start = boundary;
+    }
+    syllables.push(chars[start..].iter().collect());
+    syllables
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_single_intervocalic_consonant_attaches_to_following_syllable() {
+        assert_eq!(syllabify("araba"), vec!["a", "ra", "ba"]);
+    }
+
+    #[test]
+    fn test_consonant_cluster_splits_coda_from_onset() {
+        // "elma" splits el-ma, not e-lma: the two-consonant run closes the
+        // first syllable with "l" and opens the second with "m".
+        assert_eq!(syllabify("elma"), vec!["el", "ma"]);
+    }
+
+    #[test]
+    fn test_leading_onset_before_first_vowel() {
+        assert_eq!(syllabify("kitap"), vec!["ki", "tap"]);
+    }
+
+    #[test]
+    fn test_adjacent_vowels_split_at_the_vowel_boundary() {
+        assert_eq!(syllabify("aile"), vec!["a", "i", "le"]);
+    }
+
+    #[test]
+    fn test_three_syllable_word_with_mixed_clusters() {
+        assert_eq!(syllabify("kitaplar"), vec!["ki", "tap", "lar"]);
+    }
+
+    #[test]
+    fn test_word_with_no_vowels_is_returned_whole() {
+        assert_eq!(syllabify("tv"), vec!["tv"]);
+    }
+}
@@ -84,6 +84,88 @@ pub fn check_harmony(root_vowel: VowelClass, suffix_vowel: VowelClass) -> bool {
     true
 }
 
+/// Turkish consonants that trigger devoicing of a following `{D}` archiphoneme
+/// (the "fıstıkçı şahap" mnemonic set: ç, f, h, k, p, s, ş, t).
+const VOICELESS_CONSONANTS: &[char] = &['ç', 'f', 'h', 'k', 'p', 's', 'ş', 't'];
+
+/// Resolve a single archiphoneme code against the preceding stem.
+///
+/// - `A` is the low-vowel archiphoneme: realizes as `a` after a back last
+///   vowel, `e` after a front one (two-way harmony).
+/// - `I` is the high-vowel archiphoneme: realizes as `ı`/`i`/`u`/`ü` by
+///   backness and rounding of the last vowel (four-way harmony).
+/// - `D` is the voicing-alternating dental: realizes as `t` after a
+///   voiceless stem-final consonant, `d` otherwise.
+/// - `Cy`/`Cn`/`Cs` are buffer consonants: they surface as `y`/`n`/`s`
+///   after a vowel-final stem (e.g. dative `kapı` + `ya`, genitive `araba`
+///   + `nın`, 3sg possessive `kapı` + `sı`) and drop entirely after a
+///   consonant-final stem (`ev` + `a`, `kitap` + `ın`, `kitap` + `ı`).
+fn resolve_archiphoneme(code: &str, stem: &str, last_vowel: Option<VowelClass>) -> String {
+    match code {
+        "A" => match last_vowel {
+            Some(v) if v.is_front() => "e".to_string(),
+            _ => "a".to_string(),
+        },
+        "I" => match last_vowel {
+            Some(VowelClass::FrontUnrounded) => "i".to_string(),
+            Some(VowelClass::FrontRounded) => "ü".to_string(),
+            Some(VowelClass::BackRounded) => "u".to_string(),
+            _ => "ı".to_string(),
+        },
+        "D" => {
+            let voiceless = stem
+                .chars()
+                .last()
+                .map_or(false, |c| VOICELESS_CONSONANTS.contains(&c));
+            if voiceless { "t".to_string() } else { "d".to_string() }
+        }
+        "Cy" | "Cn" | "Cs" => {
+            let stem_ends_in_vowel = stem.chars().last().map_or(false, |c| get_vowel_class(c).is_some());
+            if stem_ends_in_vowel {
+                code[1..].to_string()
+            } else {
+                String::new()
+            }
+        }
+        _ => String::new(),
+    }
+}
+
+/// Realize an abstract morpheme template against a stem, resolving every
+/// `{A}`/`{I}`/`{D}`/`{Cy}`/`{Cn}`/`{Cs}` archiphoneme by vowel harmony and
+/// consonant assimilation. Literal characters in the template pass through
+/// unchanged, so a suffix like the locative is stored once as `"{D}{A}"`
+/// and expands to `da`/`de`/`ta`/`te` depending on the stem.
+///
+/// # Examples
+/// - `realize("{D}{A}", "kitap")` → `"ta"` (voiceless-final, back vowel)
+/// - `realize("{D}{A}", "ev")` → `"de"` (voiced-final, front vowel)
+/// - `realize("{I}m", "kitap")` → `"ım"`
+/// - `realize("{I}m", "göz")` → `"üm"`
+pub fn realize(template: &str, stem: &str) -> String {
+    let last_vowel = get_last_vowel_class(stem);
+    let mut out = String::new();
+    let mut chars = template.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c != '{' {
+            out.push(c);
+            continue;
+        }
+
+        let mut code = String::new();
+        for c2 in chars.by_ref() {
+            if c2 == '}' {
+                break;
+            }
+            code.push(c2);
+        }
+        out.push_str(&resolve_archiphoneme(&code, stem, last_vowel));
+    }
+
+    out
+}
+
 /// Check if a suffix string harmonizes with a root string
 /// 
 /// Validates that all vowels in the suffix harmonize with the last vowel in the root.
@@ -247,4 +329,38 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_realize_a_harmony() {
+        assert_eq!(realize("{A}", "kitap"), "a");
+        assert_eq!(realize("{A}", "ev"), "e");
+    }
+
+    #[test]
+    fn test_realize_i_harmony_four_way() {
+        assert_eq!(realize("{I}m", "kitap"), "ım"); // back unrounded
+        assert_eq!(realize("{I}m", "göz"), "üm"); // front rounded
+        assert_eq!(realize("{I}m", "ev"), "im"); // front unrounded
+        assert_eq!(realize("{I}m", "kol"), "um"); // back rounded
+    }
+
+    #[test]
+    fn test_realize_locative_template() {
+        // {D}{A} stored once, realized to da/de/ta/te by context
+        assert_eq!(realize("{D}{A}", "ev"), "de");
+        assert_eq!(realize("{D}{A}", "oda"), "da");
+        assert_eq!(realize("{D}{A}", "kitap"), "ta"); // voiceless-final
+        assert_eq!(realize("{D}{A}", "ip"), "te"); // voiceless-final, front
+    }
+
+    #[test]
+    fn test_realize_possessive_buffer_consonant() {
+        // Vowel-final stems take the buffer; consonant-final stems don't.
+        assert_eq!(realize("{Cy}a", "kapı"), "ya");
+        assert_eq!(realize("{Cy}a", "ev"), "a");
+        assert_eq!(realize("{Cn}ın", "araba"), "nın");
+        assert_eq!(realize("{Cn}ın", "kitap"), "ın");
+        assert_eq!(realize("{Cs}ı", "kapı"), "sı");
+        assert_eq!(realize("{Cs}ı", "kitap"), "ı");
+    }
 }